@@ -0,0 +1,234 @@
+use super::core::{FromBits, TrailingBits};
+use crate::{BitsRef, Error};
+use alloc::vec::Vec;
+
+/**
+ *  `BitVec` is a growable buffer of bits, which keeps the exact
+ *  number of bits beside the bytes. Bits are stored from the
+ *  highest bit of the first byte, the unused bits of the last
+ *  byte are always zero.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Creates an empty `BitVec`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty `BitVec` with capacity of at least `bits` bits.
+    #[inline(always)]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(bits.div_ceil(8)),
+            len: 0,
+        }
+    }
+
+    /// Number of bits
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Bit at `index`, `None` if out of bounds
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        match index < self.len {
+            true => Some(bit_at(&self.bytes, index)),
+            false => None,
+        }
+    }
+
+    /// Appends a bit.
+    /// # Examples
+    /// ```
+    /// # use nbits::BitVec;
+    /// let mut bits = BitVec::new();
+    /// bits.push(true);
+    /// bits.push(false);
+    /// bits.push(true);
+    /// assert_eq!(bits.len(), 3);
+    /// assert_eq!(bits.as_bytes(), [0b1010_0000]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Removes the last bit and returns it, `None` if empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<bool> {
+        let bit = self.get(self.len.checked_sub(1)?)?;
+        self.len -= 1;
+        self.truncate_bytes();
+        Some(bit)
+    }
+
+    /// Shortens to the first `len` bits, no effect if `len` is greater than the length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+            self.truncate_bytes();
+        }
+    }
+
+    /// Iterator over the bits
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+        (0..self.len).map(|index| bit_at(&self.bytes, index))
+    }
+
+    /// The bytes, the unused bits of the last byte are zero
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Bit operations view of the bytes, including the unused bits of the last byte
+    #[inline(always)]
+    pub fn bits(&self) -> BitsRef<'_> {
+        BitsRef(&self.bytes)
+    }
+
+    /// Unwraps the bytes, the unused bits of the last byte are zero.
+    #[inline(always)]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Drop bytes after the length and clear the unused bits
+    fn truncate_bytes(&mut self) {
+        self.bytes.truncate(self.len.div_ceil(8));
+        if let Some(last) = self
+            .bytes
+            .last_mut()
+            .filter(|_| !self.len.is_multiple_of(8))
+        {
+            *last &= 0xff << (8 - self.len % 8);
+        }
+    }
+}
+
+/// Bit at `index` of the bytes
+#[inline(always)]
+fn bit_at(bytes: &[u8], index: usize) -> bool {
+    bytes[index / 8] >> (7 - index % 8) & 1 == 1
+}
+
+impl From<Vec<u8>> for BitVec {
+    #[inline(always)]
+    fn from(bytes: Vec<u8>) -> Self {
+        let len = bytes.len() * 8;
+        Self { bytes, len }
+    }
+}
+
+impl From<BitVec> for Vec<u8> {
+    #[inline(always)]
+    fn from(bits: BitVec) -> Self {
+        bits.bytes
+    }
+}
+
+/// # Examples
+/// ```
+/// # use nbits::BitVec;
+/// let bits: BitVec = [true, true, false, true].into_iter().collect();
+/// assert_eq!(bits.len(), 4);
+/// assert_eq!(bits.as_bytes(), [0b1101_0000]);
+/// ```
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = Self::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+/// # Examples
+/// ```
+/// # use nbits::BitVec;
+/// let mut bits = BitVec::from(vec![0xff]);
+/// bits.extend([false, true]);
+/// assert_eq!(bits.len(), 10);
+/// assert_eq!(bits.as_bytes(), [0xff, 0b0100_0000]);
+/// ```
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.bytes
+            .reserve((self.len + iter.size_hint().0).div_ceil(8) - self.bytes.len());
+        iter.for_each(|bit| self.push(bit));
+    }
+}
+
+/// The length is the exact number of conjoined bits, without the padding of the last byte.
+impl FromBits for BitVec {
+    #[inline]
+    fn from_bits<U>(bits: U) -> Self
+    where
+        U: Iterator<Item = bool>,
+    {
+        bits.collect()
+    }
+
+    fn from_bits_chunk<T, U>(chunks: U, n: usize) -> Self
+    where
+        T: TryInto<u128>,
+        U: Iterator<Item = T>,
+    {
+        let mut count = 0;
+        let bytes = Vec::from_bits_chunk(chunks.inspect(|_| count += 1), n);
+        Self {
+            bytes,
+            len: count * n,
+        }
+    }
+
+    #[inline]
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        Self::from_bits_chunk_with(chunks, n, TrailingBits::Keep)
+    }
+
+    fn from_bits_chunk_with<T, U>(
+        chunks: U,
+        n: usize,
+        trailing: TrailingBits,
+    ) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        let mut count = 0;
+        let bytes = Vec::from_bits_chunk_with(chunks.inspect(|_| count += 1), n, trailing)?;
+        let len = match trailing {
+            TrailingBits::Keep => count * n,
+            _ => bytes.len() * 8,
+        };
+        Ok(Self { bytes, len })
+    }
+}
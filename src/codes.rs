@@ -0,0 +1,387 @@
+//! Variable-length integer codes
+//!
+//! Codes are read from any [`BitRead`] and written to any [`BitWrite`],
+//! such as [`BitCursor`](crate::core::BitCursor) over a byte buffer
+//! or [`BitReader`](crate::io::BitReader) over a stream.
+//!
+//! | value | Exp-Golomb | Elias gamma | Elias delta | Elias omega | Rice k = 1 |
+//! |-------|------------|-------------|-------------|-------------|------------|
+//! | 0     | `1`        |             |             |             | `00`       |
+//! | 1     | `010`      | `1`         | `1`         | `0`         | `01`       |
+//! | 2     | `011`      | `010`       | `0100`      | `100`       | `100`      |
+//! | 3     | `00100`    | `011`       | `0101`      | `110`       | `101`      |
+//! | 4     | `00101`    | `00100`     | `01100`     | `101000`    | `1100`     |
+//!
+//! # Examples
+//! ```
+//! use nbits::codes::{CodeRead, CodeWrite};
+//! use nbits::core::{BitCursor, BitCursorMut};
+//!
+//! let mut data = [0_u8; 2];
+//! let mut cursor = BitCursorMut::new(&mut data);
+//! cursor.write_exp_golomb(3).unwrap();
+//! cursor.write_signed_exp_golomb(-1).unwrap();
+//! cursor.write_elias_gamma(4).unwrap();
+//! assert_eq!(data, [0b00100_011, 0b00100_000]);
+//!
+//! let mut cursor = BitCursor::new(&data);
+//! assert_eq!(cursor.read_exp_golomb(), Ok(3));
+//! assert_eq!(cursor.read_signed_exp_golomb(), Ok(-1));
+//! assert_eq!(cursor.read_elias_gamma(), Ok(4));
+//! ```
+
+use crate::core::{BitRead, BitWrite};
+use crate::{assert_overflow, Error};
+
+/**
+ * Variable-length code decoders for any bit source
+ *
+ * Codes whose value overflows the decoded type are `Error::InvalidCode`.
+ */
+pub trait CodeRead: BitRead {
+    /// Read unsigned Exp-Golomb code `ue(v)`
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b1_010_011_0, 0b0100_0000]);
+    /// let values = (0..4).map(|_| cursor.read_exp_golomb().unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// ```
+    fn read_exp_golomb(&mut self) -> Result<u64, Self::Error> {
+        let value = read_exp_golomb_raw(self)?;
+        u64::try_from(value).map_err(|_| Error::InvalidCode.into())
+    }
+
+    /// Read signed Exp-Golomb code `se(v)`, positive values map to odd codes
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b1_010_011_0, 0b0100_0000]);
+    /// let values = (0..4).map(|_| cursor.read_signed_exp_golomb().unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [0, 1, -1, 2]);
+    /// ```
+    fn read_signed_exp_golomb(&mut self) -> Result<i64, Self::Error> {
+        let code = read_exp_golomb_raw(self)? as i128;
+        let value = match code & 1 {
+            1 => (code + 1) / 2,
+            _ => -(code / 2),
+        };
+        i64::try_from(value).map_err(|_| Error::InvalidCode.into())
+    }
+
+    /// Read Elias gamma code of a value >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b1_010_011_0, 0b0100_0000]);
+    /// let values = (0..4).map(|_| cursor.read_elias_gamma().unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    fn read_elias_gamma(&mut self) -> Result<u64, Self::Error> {
+        let n = read_zeros(self, u64::BITS as usize - 1)?;
+        Ok(1 << n | self.read_bits(n)?)
+    }
+
+    /// Read Elias delta code of a value >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b1_0100_010, 0b1_01100_00]);
+    /// let values = (0..4).map(|_| cursor.read_elias_delta().unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    fn read_elias_delta(&mut self) -> Result<u64, Self::Error> {
+        let len = self.read_elias_gamma()?;
+        if len > u64::BITS as u64 {
+            return Err(Error::InvalidCode.into());
+        }
+        let n = len as usize - 1;
+        Ok(1 << n | self.read_bits(n)?)
+    }
+
+    /// Read Elias omega code of a value >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b0_100_110_1, 0b01000_000]);
+    /// let values = (0..4).map(|_| cursor.read_elias_omega().unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    fn read_elias_omega(&mut self) -> Result<u64, Self::Error> {
+        let mut value = 1_u64;
+        while self.read_bit()? {
+            if value >= u64::BITS as u64 {
+                return Err(Error::InvalidCode.into());
+            }
+            value = 1 << value | self.read_bits(value as usize)?;
+        }
+        Ok(value)
+    }
+
+    /// Read Rice code, the quotient in unary ones and the lowest k bits
+    /// # Parameters
+    /// - `k`: the number of remainder bits, 0 <= k <= 64
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b00_01_100_1, 0b01_1100_00]);
+    /// let values = (0..5).map(|_| cursor.read_rice(1).unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    /// ```
+    fn read_rice(&mut self, k: usize) -> Result<u64, Self::Error> {
+        assert_overflow!(k, 0, u64::BITS, "read_rice");
+        let q = read_unary(self)? as u128;
+        let value = q << k | self.read_bits(k)? as u128;
+        u64::try_from(value).map_err(|_| Error::InvalidCode.into())
+    }
+
+    /// Read Golomb code, the quotient in unary ones and the remainder in truncated binary
+    /// # Parameters
+    /// - `m`: the divisor, m >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeRead;
+    /// # use nbits::core::BitCursor;
+    /// let mut cursor = BitCursor::new(&[0b00_010_011, 0b100_1010_0]);
+    /// let values = (0..5).map(|_| cursor.read_golomb(3).unwrap());
+    /// assert_eq!(values.collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    /// ```
+    fn read_golomb(&mut self, m: u64) -> Result<u64, Self::Error> {
+        let (b, cutoff) = golomb_params(m, "read_golomb");
+        let q = read_unary(self)? as u128;
+        let r = match b {
+            0 => 0,
+            _ => {
+                let x = self.read_bits(b - 1)? as u128;
+                match x < cutoff {
+                    true => x,
+                    false => (x << 1 | self.read_bit()? as u128) - cutoff,
+                }
+            }
+        };
+        u64::try_from(q * m as u128 + r).map_err(|_| Error::InvalidCode.into())
+    }
+}
+
+impl<S: BitRead + ?Sized> CodeRead for S {}
+
+/**
+ * Variable-length code encoders for any bit sink
+ *
+ * Values without a code, such as 0 in Elias codes, are `Error::Unrepresentable`.
+ */
+pub trait CodeWrite: BitWrite {
+    /// Write unsigned Exp-Golomb code `ue(v)`
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// (0..4).for_each(|v| cursor.write_exp_golomb(v).unwrap());
+    /// assert_eq!(data, [0b1_010_011_0, 0b0100_0000]);
+    /// ```
+    fn write_exp_golomb(&mut self, value: u64) -> Result<(), Self::Error> {
+        write_exp_golomb_raw(self, value as u128)
+    }
+
+    /// Write signed Exp-Golomb code `se(v)`, positive values map to odd codes
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// [0, 1, -1, 2].into_iter().for_each(|v| cursor.write_signed_exp_golomb(v).unwrap());
+    /// assert_eq!(data, [0b1_010_011_0, 0b0100_0000]);
+    /// ```
+    fn write_signed_exp_golomb(&mut self, value: i64) -> Result<(), Self::Error> {
+        let value = value as i128;
+        let code = match value > 0 {
+            true => 2 * value - 1,
+            false => -2 * value,
+        };
+        write_exp_golomb_raw(self, code as u128)
+    }
+
+    /// Write Elias gamma code of a value >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// (1..5).for_each(|v| cursor.write_elias_gamma(v).unwrap());
+    /// assert!(cursor.write_elias_gamma(0).is_err());
+    /// assert_eq!(data, [0b1_010_011_0, 0b0100_0000]);
+    /// ```
+    fn write_elias_gamma(&mut self, value: u64) -> Result<(), Self::Error> {
+        let n = floor_log2(value)?;
+        self.write_bits(0, n)?;
+        self.write_bits(value, n + 1)
+    }
+
+    /// Write Elias delta code of a value >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// (1..5).for_each(|v| cursor.write_elias_delta(v).unwrap());
+    /// assert_eq!(data, [0b1_0100_010, 0b1_01100_00]);
+    /// ```
+    fn write_elias_delta(&mut self, value: u64) -> Result<(), Self::Error> {
+        let n = floor_log2(value)?;
+        self.write_elias_gamma(n as u64 + 1)?;
+        self.write_bits(value ^ 1 << n, n)
+    }
+
+    /// Write Elias omega code of a value >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// (1..5).for_each(|v| cursor.write_elias_omega(v).unwrap());
+    /// assert_eq!(data, [0b0_100_110_1, 0b01000_000]);
+    /// ```
+    fn write_elias_omega(&mut self, value: u64) -> Result<(), Self::Error> {
+        floor_log2(value)?;
+        // groups are written in reverse order of computing, a u64 has at most 5 groups
+        let mut groups = [(0_u64, 0_usize); 8];
+        let (mut count, mut value) = (0, value);
+        while value > 1 {
+            let len = floor_log2(value)? + 1;
+            groups[count] = (value, len);
+            count += 1;
+            value = len as u64 - 1;
+        }
+        for &(value, len) in groups[..count].iter().rev() {
+            self.write_bits(value, len)?;
+        }
+        self.write_bit(false)
+    }
+
+    /// Write Rice code, the quotient in unary ones and the lowest k bits
+    /// # Parameters
+    /// - `k`: the number of remainder bits, 0 <= k <= 64
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// (0..5).for_each(|v| cursor.write_rice(v, 1).unwrap());
+    /// assert_eq!(data, [0b00_01_100_1, 0b01_1100_00]);
+    /// ```
+    fn write_rice(&mut self, value: u64, k: usize) -> Result<(), Self::Error> {
+        assert_overflow!(k, 0, u64::BITS, "write_rice");
+        let (q, r) = match k {
+            64 => (0, value),
+            _ => (value >> k, value & !(u64::MAX << k)),
+        };
+        write_unary(self, q)?;
+        self.write_bits(r, k)
+    }
+
+    /// Write Golomb code, the quotient in unary ones and the remainder in truncated binary
+    /// # Parameters
+    /// - `m`: the divisor, m >= 1
+    /// # Examples
+    /// ```
+    /// # use nbits::codes::CodeWrite;
+    /// # use nbits::core::BitCursorMut;
+    /// let mut data = [0_u8; 2];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// (0..5).for_each(|v| cursor.write_golomb(v, 3).unwrap());
+    /// assert_eq!(data, [0b00_010_011, 0b100_1010_0]);
+    /// ```
+    fn write_golomb(&mut self, value: u64, m: u64) -> Result<(), Self::Error> {
+        let (b, cutoff) = golomb_params(m, "write_golomb");
+        write_unary(self, value / m)?;
+        let r = (value % m) as u128;
+        match b {
+            0 => Ok(()),
+            _ if r < cutoff => self.write_bits(r as u64, b - 1),
+            _ => self.write_bits((r + cutoff) as u64, b),
+        }
+    }
+}
+
+impl<S: BitWrite + ?Sized> CodeWrite for S {}
+
+/// Position of the highest one bit, 0 has no code
+#[inline(always)]
+fn floor_log2(value: u64) -> Result<usize, Error> {
+    match value {
+        0 => Err(Error::Unrepresentable { value: 0 }),
+        _ => Ok(value.ilog2() as usize),
+    }
+}
+
+/// Remainder bits and truncated binary cutoff of Golomb divisor m
+#[inline]
+fn golomb_params(m: u64, name: &str) -> (usize, u128) {
+    assert!(
+        m != 0,
+        "[nbits] {name} parameter `m` overflow: `0` not in `1..={}`",
+        u64::MAX
+    );
+    let b = (u64::BITS - (m - 1).leading_zeros()) as usize;
+    (b, (1 << b) - m as u128)
+}
+
+/// Count zeros before a one, which is consumed, at most `max` zeros
+fn read_zeros<S: BitRead + ?Sized>(source: &mut S, max: usize) -> Result<usize, S::Error> {
+    let mut n = 0;
+    while !source.read_bit()? {
+        n += 1;
+        if n > max {
+            return Err(Error::InvalidCode.into());
+        }
+    }
+    Ok(n)
+}
+
+/// Count ones before a zero, which is consumed
+fn read_unary<S: BitRead + ?Sized>(source: &mut S) -> Result<u64, S::Error> {
+    let mut q = 0_u64;
+    while source.read_bit()? {
+        q = q.checked_add(1).ok_or(Error::InvalidCode)?;
+    }
+    Ok(q)
+}
+
+/// Write q ones and a zero
+fn write_unary<S: BitWrite + ?Sized>(sink: &mut S, mut q: u64) -> Result<(), S::Error> {
+    while q >= u64::BITS as u64 {
+        sink.write_bits(u64::MAX, u64::BITS as usize)?;
+        q -= u64::BITS as u64;
+    }
+    sink.write_bits(!(u64::MAX << q), q as usize)?;
+    sink.write_bit(false)
+}
+
+/// Read Exp-Golomb code of a value < 2^65 - 1, as signed codes take up to 2^64
+fn read_exp_golomb_raw<S: BitRead + ?Sized>(source: &mut S) -> Result<u128, S::Error> {
+    let n = read_zeros(source, u64::BITS as usize)?;
+    let x = 1 << n | source.read_bits(n)? as u128;
+    Ok(x - 1)
+}
+
+/// Write Exp-Golomb code of a value <= 2^64
+fn write_exp_golomb_raw<S: BitWrite + ?Sized>(sink: &mut S, value: u128) -> Result<(), S::Error> {
+    let x = value + 1;
+    let n = x.ilog2() as usize;
+    sink.write_bits(0, n)?;
+    sink.write_bit(true)?;
+    sink.write_bits((x ^ 1 << n) as u64, n)
+}
@@ -1,5 +1,6 @@
-use super::BitIterator;
-use super::Bitwise;
+use super::{BitIterator, Bitwise};
+#[cfg(feature = "alloc")]
+use alloc::vec;
 
 /**
  * Arithmetic operations implementation for `[u8]`
@@ -17,7 +18,19 @@ pub trait BitArith {
     /// assert_eq!([0b0011_0011, 0b0011_0011].bit_be_cmp(&[0b0000_0000, 0b1111_1111]), Ordering::Greater);
     /// assert_eq!([0b0011_0011, 0b0011_0011].bit_be_cmp(&[0b1111_1111, 0b0000_0000]), Ordering::Less);
     /// ```
-    fn bit_be_cmp(&self, other: &Self) -> std::cmp::Ordering;
+    fn bit_be_cmp(&self, other: &Self) -> core::cmp::Ordering;
+
+    /// Comparison for little-endian
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitArith;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!([0b0011_0011, 0b0011_0011].bit_le_cmp(&[0b1111_1111]), Ordering::Greater);
+    /// assert_eq!([0b0011_0011, 0b0000_0000].bit_le_cmp(&[0b1111_1111]), Ordering::Less);
+    /// assert_eq!([0b0011_0011, 0b0011_0011].bit_le_cmp(&[0b1111_1111, 0b0000_0000]), Ordering::Greater);
+    /// assert_eq!([0b0011_0011, 0b0011_0011].bit_le_cmp(&[0b0000_0000, 0b1111_1111]), Ordering::Less);
+    /// ```
+    fn bit_le_cmp(&self, other: &Self) -> core::cmp::Ordering;
 
     /// Bit arithmetic operator `+=` for big-endian
     /// # Example
@@ -47,6 +60,7 @@ pub trait BitArith {
     /// assert_eq!(a.as_mut().bit_be_mul(&b), false);
     /// assert_eq!(a, [0b0110_0001, 0b0000_0010]);
     /// ```
+    #[cfg(feature = "alloc")]
     fn bit_be_mul(&mut self, other: &Self::Other) -> bool;
 
     /// Bit arithmetic operator `/=` for big-endian
@@ -58,6 +72,7 @@ pub trait BitArith {
     /// x.as_mut().bit_be_div(&b);
     /// assert_eq!(x, (u16::from_be_bytes(a) / u16::from_be_bytes([0, b[0]])).to_be_bytes());
     /// ```
+    #[cfg(feature = "alloc")]
     fn bit_be_div(&mut self, other: &Self::Other) -> bool;
 
     /// Bit arithmetic operator `%=` for big-endian
@@ -67,24 +82,65 @@ pub trait BitArith {
     /// let (a, b) = ([0b1100_0011, 0b0000_0001], [0b0000_0001, 0b1000_0001]);
     /// let mut x = a.clone();
     /// x.as_mut().bit_be_rem(&b);
-    /// // assert_eq!(x, (u16::from_be_bytes(a) % u16::from_be_bytes(b)).to_be_bytes());
+    /// assert_eq!(x, (u16::from_be_bytes(a) % u16::from_be_bytes(b)).to_be_bytes());
     /// ```
+    #[cfg(feature = "alloc")]
     fn bit_be_rem(&mut self, other: &Self::Other) -> bool;
+
+    /// Bit arithmetic operator `*=` for big-endian, without allocation,
+    /// `scratch` must have at least the length of `self`
+    /// # Example
+    /// ```
+    /// # use nbits::core::BitArith;
+    /// let (mut a, b, mut scratch) = ([0b0011_0000, 0b1000_0001], [0b0000_0010], [0; 2]);
+    /// assert_eq!(a.as_mut().bit_be_mul_with_scratch(&b, &mut scratch), false);
+    /// assert_eq!(a, [0b0110_0001, 0b0000_0010]);
+    /// ```
+    fn bit_be_mul_with_scratch(&mut self, other: &Self::Other, scratch: &mut [u8]) -> bool;
+
+    /// Bit arithmetic operator `/=` for big-endian, without allocation,
+    /// `scratch` must have at least the length of `self`
+    /// # Example
+    /// ```
+    /// # use nbits::core::BitArith;
+    /// let (mut a, b, mut scratch) = ([0x01, 0x00], [0x00, 0x03], [0; 2]);
+    /// assert_eq!(a.as_mut().bit_be_div_with_scratch(&b, &mut scratch), false);
+    /// assert_eq!(a, [0x00, 85]);
+    /// ```
+    fn bit_be_div_with_scratch(&mut self, other: &Self::Other, scratch: &mut [u8]) -> bool;
+
+    /// Bit arithmetic operator `%=` for big-endian, without allocation,
+    /// `scratch` must have at least the length of `self`
+    /// # Example
+    /// ```
+    /// # use nbits::core::BitArith;
+    /// let (mut a, b, mut scratch) = ([0x01, 0x00], [0x03], [0; 2]);
+    /// assert_eq!(a.as_mut().bit_be_rem_with_scratch(&b, &mut scratch), false);
+    /// assert_eq!(a, [0x00, 1]);
+    /// ```
+    fn bit_be_rem_with_scratch(&mut self, other: &Self::Other, scratch: &mut [u8]) -> bool;
 }
 
 impl BitArith for [u8] {
     type Other = Self;
 
-    fn bit_be_cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let max_len = std::cmp::max(self.len(), other.len());
+    fn bit_be_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let max_len = core::cmp::max(self.len(), other.len());
         self.extend_be_iter(max_len)
             .cmp(other.extend_be_iter(max_len))
     }
 
+    fn bit_le_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let max_len = core::cmp::max(self.len(), other.len());
+        self.extend_le_iter(max_len)
+            .rev()
+            .cmp(other.extend_le_iter(max_len).rev())
+    }
+
     fn bit_be_add(&mut self, other: &Self) -> bool {
         self.iter_mut()
             .rev()
-            .zip(other.iter().rev().chain(std::iter::repeat(&0)))
+            .zip(other.iter().rev().chain(core::iter::repeat(&0)))
             .fold(false, |mut carry, (a, b)| {
                 match (carry, *b) {
                     (true, 0xff) => carry = true,
@@ -98,7 +154,7 @@ impl BitArith for [u8] {
     fn bit_be_sub(&mut self, other: &Self) -> bool {
         self.iter_mut()
             .rev()
-            .zip(other.iter().rev().chain(std::iter::repeat(&0)))
+            .zip(other.iter().rev().chain(core::iter::repeat(&0)))
             .fold(false, |mut borrow, (a, b)| {
                 match (borrow, *b) {
                     (true, 0xff) => borrow = true,
@@ -109,116 +165,111 @@ impl BitArith for [u8] {
             })
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
     fn bit_be_mul(&mut self, other: &Self) -> bool {
-        let mut result = vec![0; self.len()];
-        let mut overflow = false;
-        for (i, bit) in other.bit_iter().rev().enumerate() {
-            if bit {
-                let mut multiple = self.to_vec();
-                overflow |= multiple.bit_shl(i);
-                overflow |= result.bit_be_add(&multiple);
-            }
-        }
-        self.copy_from_slice(&result);
-        overflow
+        self.bit_be_mul_with_scratch(other, &mut vec![0; self.len()])
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
     fn bit_be_div(&mut self, other: &Self) -> bool {
-        if other.iter().all(|&b| b == 0) {
-            return true; // Division by zero, return overflow
-        }
+        self.bit_be_div_with_scratch(other, &mut vec![0; self.len()])
+    }
 
-        // Ignore leading zeros
-        let bits_a = self.len() * 8 - self.bit_leading_zeros(); // effective bits length
-        let bits_b = other.len() * 8 - other.bit_leading_zeros(); // effective bits length
-        if bits_a < bits_b {
-            self.fill(0);
-            return false;
-        }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn bit_be_rem(&mut self, other: &Self) -> bool {
+        self.bit_be_rem_with_scratch(other, &mut vec![0; self.len()])
+    }
 
-        let mut other = other.extend_be(self.len()); // extend to the same length
-        {
-            // Remove common trailing zeros
-            let common_divisor_bits = self.bit_trailing_zeros().min(other.bit_trailing_zeros());
-            self.bit_shr(common_divisor_bits);
-            other.bit_shr(common_divisor_bits);
+    fn bit_be_mul_with_scratch(&mut self, other: &Self, scratch: &mut [u8]) -> bool {
+        // Horner's method from the highest bit of `other`: result = result * 2 + bit * self,
+        // the partial products only grow, so any lost bit means overflow.
+        let result = zeroed(scratch, self.len());
+        let mut overflow = false;
+        for bit in other.bit_iter().skip(other.bit_leading_zeros()) {
+            overflow |= result.bit_shl(1);
+            if bit {
+                overflow |= result.bit_be_add(self);
+            }
         }
+        self.copy_from_slice(result);
+        overflow
+    }
 
-        // Perform division
-        let n = self.len();
-        let mut result = vec![0; n];
-        let diff = bits_a - bits_b;
-        other.bit_shl(diff);
-        for i in (0..=diff).rev() {
-            if self.bit_be_cmp(&other) != std::cmp::Ordering::Less {
-                self.bit_be_sub(&other);
-                result[n - 1 - i / 8] |= 1 << (i % 8);
+    fn bit_be_div_with_scratch(&mut self, other: &Self, scratch: &mut [u8]) -> bool {
+        let rem = zeroed(scratch, self.len());
+        match other.bit_all_zero() {
+            true => true, // Division by zero, return overflow
+            false => {
+                long_division(self, other, rem);
+                false
             }
-            other.bit_shr(1);
         }
-
-        self.copy_from_slice(&result);
-        false
     }
 
-    fn bit_be_rem(&mut self, other: &Self) -> bool {
-        if other.iter().all(|&b| b == 0) {
-            return true; // Division by zero, return overflow
+    fn bit_be_rem_with_scratch(&mut self, other: &Self, scratch: &mut [u8]) -> bool {
+        let rem = zeroed(scratch, self.len());
+        match other.bit_all_zero() {
+            true => true, // Division by zero, return overflow
+            false => {
+                long_division(self, other, rem);
+                self.copy_from_slice(rem);
+                false
+            }
         }
+    }
+}
 
-        // Ignore leading zeros
-        let bits_a = self.len() * 8 - self.bit_leading_zeros(); // effective bits length
-        let bits_b = other.len() * 8 - other.bit_leading_zeros(); // effective bits length
-        if bits_a < bits_b {
-            return false;
-        }
+/// The first `len` bytes of `scratch` filled with zero
+#[inline(always)]
+fn zeroed(scratch: &mut [u8], len: usize) -> &mut [u8] {
+    assert!(
+        scratch.len() >= len,
+        "[nbits] scratch of `{}` bytes is shorter than `{len}` bytes",
+        scratch.len()
+    );
+    let scratch = &mut scratch[..len];
+    scratch.fill(0);
+    scratch
+}
 
-        let mut other = other.extend_be(self.len()); // extend to the same length
-        {
-            // Remove common trailing zeros
-            let common_divisor_bits = self.bit_trailing_zeros().min(other.bit_trailing_zeros());
-            self.bit_shr(common_divisor_bits);
-            other.bit_shr(common_divisor_bits);
+/// Binary long division, replaces `data` with the quotient bit by bit
+/// from the highest one, and leaves the remainder in `rem` of the same length.
+fn long_division(data: &mut [u8], divisor: &[u8], rem: &mut [u8]) {
+    for i in 0..data.len() * 8 {
+        let (byte, mask) = (i / 8, 0x80 >> (i % 8));
+        // rem = rem * 2 + bit, the lost bit means rem is greater than divisor
+        let carry = rem.bit_shl(1);
+        if let Some(last) = rem.last_mut() {
+            *last |= (data[byte] & mask != 0) as u8;
         }
-
-        // Perform division
-        let n = self.len();
-        let mut result = vec![0; n];
-        let diff = bits_a - bits_b;
-        other.bit_shl(diff);
-        for i in (0..=diff).rev() {
-            if self.bit_be_cmp(&other) != std::cmp::Ordering::Less {
-                self.bit_be_sub(&other);
-                result[n - 1 - i / 8] |= 1 << (i % 8);
+        match carry || rem.bit_be_cmp(divisor).is_ge() {
+            true => {
+                rem.bit_be_sub(divisor);
+                data[byte] |= mask;
             }
-            other.bit_shr(1);
+            false => data[byte] &= !mask,
         }
-        false
     }
 }
 
 trait ByteExtend {
     fn extend_be_iter(&self, n: usize) -> impl DoubleEndedIterator<Item = &u8>;
 
-    fn extend_be(&self, n: usize) -> Vec<u8>;
+    fn extend_le_iter(&self, n: usize) -> impl DoubleEndedIterator<Item = &u8>;
 }
 
 impl ByteExtend for [u8] {
     #[inline(always)]
     fn extend_be_iter(&self, n: usize) -> impl DoubleEndedIterator<Item = &u8> {
-        std::iter::repeat_n(&0, n - self.len()).chain(self.iter())
+        core::iter::repeat_n(&0, n - self.len()).chain(self.iter())
     }
 
     #[inline(always)]
-    fn extend_be(&self, n: usize) -> Vec<u8> {
-        let mut data = vec![0; n];
-        if self.len() < n {
-            data[n - self.len()..].copy_from_slice(self);
-        } else {
-            assert!(self[..self.len() - n].iter().all(|&b| b == 0));
-            data.copy_from_slice(&self[self.len() - n..]);
-        }
-        data
+    fn extend_le_iter(&self, n: usize) -> impl DoubleEndedIterator<Item = &u8> {
+        self.iter().chain(core::iter::repeat_n(&0, n - self.len()))
     }
 }
 
@@ -229,40 +280,43 @@ mod test_arith {
     #[test]
     fn test_bits_add() {
         let mut a = [0b1111_1111, 0b1111_1111];
-        assert_eq!(a.bit_be_add(&[0b0000_0001]), true);
+        assert!(a.bit_be_add(&[0b0000_0001]));
         assert_eq!(a, [0b0000_0000, 0b0000_0000]);
 
         let mut a = [0b0000_0000, 0b0000_0001];
-        assert_eq!(a.bit_be_add(&[0b1111_1111]), false);
+        assert!(!a.bit_be_add(&[0b1111_1111]));
         assert_eq!(a, [0b0000_0001, 0b0000_0000]);
     }
 
     #[test]
     fn test_bits_sub() {
         let mut a = [0b0000_0000, 0b0000_0001];
-        assert_eq!(a.bit_be_sub(&[0b1111_1111]), true);
+        assert!(a.bit_be_sub(&[0b1111_1111]));
         assert_eq!(a, [0b1111_1111, 0b0000_0010]);
 
         let mut a = [0b1111_1111, 0b0000_0000];
-        assert_eq!(a.bit_be_sub(&[0b0000_0001]), false);
+        assert!(!a.bit_be_sub(&[0b0000_0001]));
         assert_eq!(a, [0b1111_1110, 0b1111_1111]);
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_bits_mul() {
         let mut a = [0xff, 0xff];
-        assert_eq!(a.bit_be_mul(&[0b0000_0010]), true);
+        assert!(a.bit_be_mul(&[0b0000_0010]));
         assert_eq!(a, [0b1111_1111, 0b1111_1110]);
 
         let mut a = [0b0000_0001, 0b0000_0001];
-        assert_eq!(a.bit_be_mul(&[0b1111_1111]), false);
+        assert!(!a.bit_be_mul(&[0b1111_1111]));
         assert_eq!(a, [0b1111_1111, 0b1111_1111]);
     }
 
+    #[cfg(feature = "alloc")]
     pub trait BeValue {
         fn value(&self) -> u64;
     }
 
+    #[cfg(feature = "alloc")]
     impl BeValue for [u8] {
         fn value(&self) -> u64 {
             let mut bytes = [0; 8];
@@ -272,6 +326,7 @@ mod test_arith {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_bits_div() {
         const TDATA: &[(&[u8], &[u8], &[u8])] = &[
             (&[0b0000_1100], &[0b0000_0011], &[0b0000_0100]),
@@ -281,8 +336,40 @@ mod test_arith {
         for (a, b, c) in TDATA {
             assert_eq!(a.value() / b.value(), c.value());
             let mut a = a.to_vec();
-            assert_eq!(a.bit_be_div(b), false);
+            assert!(!a.bit_be_div(b));
             assert_eq!(&a, c);
         }
     }
+
+    #[test]
+    fn test_bits_with_scratch() {
+        let mut scratch = [0xaa; 4];
+        for a in (0..=u16::MAX).step_by(251) {
+            for b in (0..=u16::MAX).step_by(257) {
+                let (bytes, other) = (a.to_be_bytes(), b.to_be_bytes());
+
+                let mut v = bytes;
+                let overflow = v.bit_be_mul_with_scratch(&other, &mut scratch);
+                assert_eq!((u16::from_be_bytes(v), overflow), a.overflowing_mul(b));
+
+                let mut v = bytes;
+                assert_eq!(v.bit_be_div_with_scratch(&other, &mut scratch), b == 0);
+                if let Some(expected) = a.checked_div(b) {
+                    assert_eq!(u16::from_be_bytes(v), expected);
+                }
+
+                let mut v = bytes;
+                assert_eq!(v.bit_be_rem_with_scratch(&other, &mut scratch), b == 0);
+                if let Some(expected) = a.checked_rem(b) {
+                    assert_eq!(u16::from_be_bytes(v), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bits_with_short_scratch() {
+        [0, 1].bit_be_mul_with_scratch(&[2], &mut [0]);
+    }
 }
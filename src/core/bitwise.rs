@@ -1,3 +1,5 @@
+use crate::Error;
+
 /**
  * Bitwise operations implementation for `[u8]`
  */
@@ -101,6 +103,59 @@ pub trait Bitwise {
     /// ```
     fn bit_le_xor(&mut self, other: &Self) -> &mut Self;
 
+    /// Bitwise operator `&` for big-endian with length policy
+    /// # Returns
+    /// - `true` if significant bits of `other` are lost by truncation
+    /// # Examples
+    /// ```
+    /// # use nbits::core::{Bitwise, LengthPolicy};
+    /// let mut data = [0b0011_0011, 0b0011_0011];
+    /// assert_eq!(data.try_bit_be_and(&[0b0000_0001, 0b1111_1111, 0b0000_1111], LengthPolicy::Truncate), Ok(true));
+    /// assert_eq!(data, [0b0011_0011, 0b0000_0011]);
+    /// assert!(data.try_bit_be_and(&[0b1111_1111], LengthPolicy::Strict).is_err());
+    /// ```
+    fn try_bit_be_and(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error>;
+
+    /// Bitwise operator `|` for big-endian with length policy
+    /// # Returns
+    /// - `true` if significant bits of `other` are lost by truncation
+    /// # Examples
+    /// ```
+    /// # use nbits::core::{Bitwise, LengthPolicy};
+    /// let mut data = [0b0011_0011, 0b0011_0011];
+    /// assert_eq!(data.try_bit_be_or(&[0, 0, 0b1111_1111], LengthPolicy::ZeroExtend), Ok(false));
+    /// assert_eq!(data, [0b0011_0011, 0b1111_1111]);
+    /// assert!(data.try_bit_be_or(&[1, 0, 0], LengthPolicy::ZeroExtend).is_err());
+    /// ```
+    fn try_bit_be_or(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error>;
+
+    /// Bitwise operator `^` for big-endian with length policy
+    /// # Returns
+    /// - `true` if significant bits of `other` are lost by truncation
+    fn try_bit_be_xor(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error>;
+
+    /// Bitwise operator `&` for little-endian with length policy
+    /// # Returns
+    /// - `true` if significant bits of `other` are lost by truncation
+    /// # Examples
+    /// ```
+    /// # use nbits::core::{Bitwise, LengthPolicy};
+    /// let mut data = [0b0011_0011, 0b0011_0011];
+    /// assert_eq!(data.try_bit_le_and(&[0b1111_1111, 0b0000_1111, 0], LengthPolicy::ZeroExtend), Ok(false));
+    /// assert_eq!(data, [0b0011_0011, 0b0000_0011]);
+    /// ```
+    fn try_bit_le_and(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error>;
+
+    /// Bitwise operator `|` for little-endian with length policy
+    /// # Returns
+    /// - `true` if significant bits of `other` are lost by truncation
+    fn try_bit_le_or(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error>;
+
+    /// Bitwise operator `^` for little-endian with length policy
+    /// # Returns
+    /// - `true` if significant bits of `other` are lost by truncation
+    fn try_bit_le_xor(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error>;
+
     /// Check if all bits are zero
     fn bit_all_zero(&self) -> bool;
 
@@ -128,6 +183,88 @@ pub trait Bitwise {
     /// assert_eq!([0b0000_0000, 0b0000_0000].bit_trailing_zeros(), 16);
     /// ```
     fn bit_trailing_zeros(&self) -> usize;
+
+    /// Count the number of leading ones
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b1110_1111, 0b1000_0011].bit_leading_ones(), 3);
+    /// assert_eq!([0b1111_1111, 0b1000_0011].bit_leading_ones(), 9);
+    /// assert_eq!([0b1111_1111, 0b1111_1111].bit_leading_ones(), 16);
+    /// ```
+    fn bit_leading_ones(&self) -> usize;
+
+    /// Count the number of trailing ones
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b0000_1111, 0b0000_0111].bit_trailing_ones(), 3);
+    /// assert_eq!([0b0000_1111, 0b1111_1111].bit_trailing_ones(), 12);
+    /// assert_eq!([0b1111_1111, 0b1111_1111].bit_trailing_ones(), 16);
+    /// ```
+    fn bit_trailing_ones(&self) -> usize;
+
+    /// Position of the first `1` bit at or after `from`
+    /// # Parameters
+    /// - `from`: bit position to start searching, 0 is the highest bit of the first byte
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b0001_0000, 0b1000_0011].bit_next_one(0), Some(3));
+    /// assert_eq!([0b0001_0000, 0b1000_0011].bit_next_one(4), Some(8));
+    /// assert_eq!([0b0001_0000, 0b1000_0011].bit_next_one(9), Some(14));
+    /// assert_eq!([0b0001_0000, 0b0000_0000].bit_next_one(4), None);
+    /// ```
+    fn bit_next_one(&self, from: usize) -> Option<usize>;
+
+    /// Position of the last `1` bit at or before `from`
+    /// # Parameters
+    /// - `from`: bit position to start searching backward, clamped to the last bit
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b0001_0000, 0b1000_0011].bit_prev_one(15), Some(15));
+    /// assert_eq!([0b0001_0000, 0b1000_0011].bit_prev_one(13), Some(8));
+    /// assert_eq!([0b0001_0000, 0b1000_0011].bit_prev_one(7), Some(3));
+    /// assert_eq!([0b0000_0000, 0b1000_0011].bit_prev_one(7), None);
+    /// ```
+    fn bit_prev_one(&self, from: usize) -> Option<usize>;
+
+    /// Position of the first `0` bit at or after `from`
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b1110_1111, 0b0111_1100].bit_next_zero(0), Some(3));
+    /// assert_eq!([0b1110_1111, 0b0111_1100].bit_next_zero(4), Some(8));
+    /// assert_eq!([0b1111_1111, 0b1111_1111].bit_next_zero(0), None);
+    /// ```
+    fn bit_next_zero(&self, from: usize) -> Option<usize>;
+
+    /// Position of the last `0` bit at or before `from`
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b1110_1111, 0b0111_1100].bit_prev_zero(15), Some(15));
+    /// assert_eq!([0b1110_1111, 0b0111_1100].bit_prev_zero(13), Some(8));
+    /// assert_eq!([0b1111_1111, 0b0111_1100].bit_prev_zero(7), None);
+    /// ```
+    fn bit_prev_zero(&self, from: usize) -> Option<usize>;
+
+    /// Count the number of `1` bits
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b1110_1111, 0b0111_1100].bit_count_ones(), 12);
+    /// ```
+    fn bit_count_ones(&self) -> usize;
+
+    /// Count the number of `0` bits
+    /// # Examples
+    /// ```
+    /// # use nbits::core::Bitwise;
+    /// assert_eq!([0b1110_1111, 0b0111_1100].bit_count_zeros(), 4);
+    /// ```
+    fn bit_count_zeros(&self) -> usize;
 }
 
 impl Bitwise for [u8] {
@@ -140,6 +277,7 @@ impl Bitwise for [u8] {
         }
 
         let (n, m) = (n / 8, n % 8);
+        let overflow = data[..n].iter().any(|&v| v != 0);
         data.copy_within(n.., 0);
         data[len - n..].fill(0);
 
@@ -148,9 +286,9 @@ impl Bitwise for [u8] {
             data.iter_mut().take(len - n).rev().for_each(|v| {
                 (*v, carry) = ((*v << m) | carry, *v >> (8 - m));
             });
-            return carry != 0;
+            return overflow || carry != 0;
         }
-        false
+        overflow
     }
 
     fn bit_shr(&mut self, n: usize) -> bool {
@@ -162,6 +300,7 @@ impl Bitwise for [u8] {
         }
 
         let (n, m) = (n / 8, n % 8);
+        let overflow = data[len - n..].iter().any(|&v| v != 0);
         data.copy_within(..len - n, n);
         data[..n].fill(0);
 
@@ -170,9 +309,9 @@ impl Bitwise for [u8] {
             data.iter_mut().skip(n).for_each(|v| {
                 (*v, carry) = ((*v >> m) | carry, *v << (8 - m));
             });
-            return carry != 0;
+            return overflow || carry != 0;
         }
-        false
+        overflow
     }
 
     #[inline]
@@ -192,7 +331,7 @@ impl Bitwise for [u8] {
     fn bit_be_and(&mut self, other: &Self) -> &mut Self {
         self.iter_mut()
             .rev()
-            .zip(other.iter().rev().chain(std::iter::repeat(&0)))
+            .zip(other.iter().rev().chain(core::iter::repeat(&0)))
             .for_each(|(a, b)| *a &= *b);
         self
     }
@@ -201,7 +340,7 @@ impl Bitwise for [u8] {
     fn bit_be_or(&mut self, other: &Self) -> &mut Self {
         self.iter_mut()
             .rev()
-            .zip(other.iter().rev())
+            .zip(other.iter().rev().chain(core::iter::repeat(&0)))
             .for_each(|(a, b)| *a |= *b);
         self
     }
@@ -210,7 +349,7 @@ impl Bitwise for [u8] {
     fn bit_be_xor(&mut self, other: &Self) -> &mut Self {
         self.iter_mut()
             .rev()
-            .zip(other.iter().rev().chain(std::iter::repeat(&0)))
+            .zip(other.iter().rev().chain(core::iter::repeat(&0)))
             .for_each(|(a, b)| *a ^= *b);
         self
     }
@@ -218,7 +357,7 @@ impl Bitwise for [u8] {
     #[inline]
     fn bit_le_and(&mut self, other: &Self) -> &mut Self {
         self.iter_mut()
-            .zip(other.iter().chain(std::iter::repeat(&0)))
+            .zip(other.iter().chain(core::iter::repeat(&0)))
             .for_each(|(a, b)| *a &= *b);
         self
     }
@@ -226,7 +365,7 @@ impl Bitwise for [u8] {
     #[inline]
     fn bit_le_or(&mut self, other: &Self) -> &mut Self {
         self.iter_mut()
-            .zip(other.iter().chain(std::iter::repeat(&0)))
+            .zip(other.iter().chain(core::iter::repeat(&0)))
             .for_each(|(a, b)| *a |= *b);
         self
     }
@@ -234,11 +373,47 @@ impl Bitwise for [u8] {
     #[inline]
     fn bit_le_xor(&mut self, other: &Self) -> &mut Self {
         self.iter_mut()
-            .zip(other.iter().chain(std::iter::repeat(&0)))
+            .zip(other.iter().chain(core::iter::repeat(&0)))
             .for_each(|(a, b)| *a ^= *b);
         self
     }
 
+    fn try_bit_be_and(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error> {
+        let (other, lost) = policy.fit_be(self.len(), other)?;
+        self.bit_be_and(other);
+        Ok(lost)
+    }
+
+    fn try_bit_be_or(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error> {
+        let (other, lost) = policy.fit_be(self.len(), other)?;
+        self.bit_be_or(other);
+        Ok(lost)
+    }
+
+    fn try_bit_be_xor(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error> {
+        let (other, lost) = policy.fit_be(self.len(), other)?;
+        self.bit_be_xor(other);
+        Ok(lost)
+    }
+
+    fn try_bit_le_and(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error> {
+        let (other, lost) = policy.fit_le(self.len(), other)?;
+        self.bit_le_and(other);
+        Ok(lost)
+    }
+
+    fn try_bit_le_or(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error> {
+        let (other, lost) = policy.fit_le(self.len(), other)?;
+        self.bit_le_or(other);
+        Ok(lost)
+    }
+
+    fn try_bit_le_xor(&mut self, other: &Self, policy: LengthPolicy) -> Result<bool, Error> {
+        let (other, lost) = policy.fit_le(self.len(), other)?;
+        self.bit_le_xor(other);
+        Ok(lost)
+    }
+
     #[inline]
     fn bit_all_zero(&self) -> bool {
         self.iter().all(|&b| b == 0)
@@ -264,6 +439,188 @@ impl Bitwise for [u8] {
             None => self.len() * 8,
         }
     }
+
+    #[inline]
+    fn bit_leading_ones(&self) -> usize {
+        self.bit_next_zero(0).unwrap_or(self.len() * 8)
+    }
+
+    #[inline]
+    fn bit_trailing_ones(&self) -> usize {
+        match self.len() {
+            0 => 0,
+            len => match self.bit_prev_zero(len * 8 - 1) {
+                Some(n) => len * 8 - 1 - n,
+                None => len * 8,
+            },
+        }
+    }
+
+    #[inline]
+    fn bit_next_one(&self, from: usize) -> Option<usize> {
+        find_next(self, from, true)
+    }
+
+    #[inline]
+    fn bit_prev_one(&self, from: usize) -> Option<usize> {
+        find_prev(self, from, true)
+    }
+
+    #[inline]
+    fn bit_next_zero(&self, from: usize) -> Option<usize> {
+        find_next(self, from, false)
+    }
+
+    #[inline]
+    fn bit_prev_zero(&self, from: usize) -> Option<usize> {
+        find_prev(self, from, false)
+    }
+
+    fn bit_count_ones(&self) -> usize {
+        let words = self.chunks_exact(8);
+        let tail = words
+            .remainder()
+            .iter()
+            .map(|b| b.count_ones())
+            .sum::<u32>();
+        words
+            .map(|w| u64::from_ne_bytes(w.try_into().unwrap()).count_ones())
+            .sum::<u32>() as usize
+            + tail as usize
+    }
+
+    #[inline]
+    fn bit_count_zeros(&self) -> usize {
+        self.len() * 8 - self.bit_count_ones()
+    }
+}
+
+/// Length policy of logical operations with operands of different lengths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthPolicy {
+    /// Operands must have the same length
+    Strict,
+    /// A shorter operand is padded with zeros,
+    /// extra high bytes of a longer operand are dropped
+    Truncate,
+    /// A shorter operand is padded with zeros,
+    /// a longer operand must have zeros in its extra high bytes
+    ZeroExtend,
+}
+
+impl LengthPolicy {
+    /// Fit big-endian operand to `len` bytes, returns the fitted operand and whether significant bits are lost
+    fn fit_be(self, len: usize, other: &[u8]) -> Result<(&[u8], bool), Error> {
+        let (extra, other) = other.split_at(other.len().saturating_sub(len));
+        self.check(len, extra, other)
+    }
+
+    /// Fit little-endian operand to `len` bytes, returns the fitted operand and whether significant bits are lost
+    fn fit_le(self, len: usize, other: &[u8]) -> Result<(&[u8], bool), Error> {
+        let (other, extra) = other.split_at(other.len().min(len));
+        self.check(len, extra, other)
+    }
+
+    fn check<'a>(
+        self,
+        len: usize,
+        extra: &[u8],
+        other: &'a [u8],
+    ) -> Result<(&'a [u8], bool), Error> {
+        let lost = !extra.bit_all_zero();
+        match self {
+            LengthPolicy::Strict if !extra.is_empty() || other.len() != len => {
+                Err(Error::LengthMismatch {
+                    expected: len,
+                    found: other.len() + extra.len(),
+                })
+            }
+            LengthPolicy::ZeroExtend if lost => Err(Error::Truncated {
+                len: other.len() + extra.len(),
+                max: len,
+            }),
+            _ => Ok((other, lost)),
+        }
+    }
+}
+
+/// Bytes of a word which contain no searched bit
+#[inline(always)]
+const fn skip_word(bit: bool) -> u64 {
+    match bit {
+        true => 0,
+        false => u64::MAX,
+    }
+}
+
+/// Byte value whose `1` bits mark the searched bit
+#[inline(always)]
+const fn match_byte(byte: u8, bit: bool) -> u8 {
+    match bit {
+        true => byte,
+        false => !byte,
+    }
+}
+
+/// Search forward for `bit`, skipping 8 bytes at a time where possible
+fn find_next(data: &[u8], from: usize, bit: bool) -> Option<usize> {
+    let mut i = from / 8;
+    if i >= data.len() {
+        return None;
+    }
+
+    // first partial byte, ignore bits before `from`
+    let v = match_byte(data[i], bit) & (0xff >> (from % 8));
+    if v != 0 {
+        return Some(i * 8 + v.leading_zeros() as usize);
+    }
+    i += 1;
+
+    while i < data.len() {
+        if let Some(word) = data.get(i..i + 8) {
+            if u64::from_ne_bytes(word.try_into().unwrap()) == skip_word(bit) {
+                i += 8;
+                continue;
+            }
+        }
+        let v = match_byte(data[i], bit);
+        if v != 0 {
+            return Some(i * 8 + v.leading_zeros() as usize);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Search backward for `bit`, skipping 8 bytes at a time where possible
+fn find_prev(data: &[u8], from: usize, bit: bool) -> Option<usize> {
+    if data.is_empty() {
+        return None;
+    }
+    let from = from.min(data.len() * 8 - 1);
+    let mut i = from / 8;
+
+    // first partial byte, ignore bits after `from`
+    let v = match_byte(data[i], bit) & (0xff << (7 - from % 8));
+    if v != 0 {
+        return Some(i * 8 + 7 - v.trailing_zeros() as usize);
+    }
+
+    while i > 0 {
+        if i >= 8 {
+            let word = &data[i - 8..i];
+            if u64::from_ne_bytes(word.try_into().unwrap()) == skip_word(bit) {
+                i -= 8;
+                continue;
+            }
+        }
+        i -= 1;
+        let v = match_byte(data[i], bit);
+        if v != 0 {
+            return Some(i * 8 + 7 - v.trailing_zeros() as usize);
+        }
+    }
+    None
 }
 
 #[cfg(test)]
@@ -273,16 +630,22 @@ mod test_offset {
     #[test]
     fn test_bit_shl() {
         let mut data: [u8; 2] = [0b1111_1111, 0b0000_0000];
-        assert_eq!(data.bit_shl(4), true);
+        assert!(data.bit_shl(4));
         assert_eq!(data, [0b1111_0000, 0b0000_0000]);
+        // a whole byte shifted out
+        assert!([0x01, 0x00].bit_shl(8));
+        assert!(![0x00, 0x01].bit_shl(8));
     }
 
     #[test]
     fn test_bit_shr() {
         let mut data: [u8; 2] = [0b1111_1111, 0b0000_0000];
-        assert_eq!(data.bit_shr(4), false);
+        assert!(!data.bit_shr(4));
         assert_eq!(data, [0b0000_1111, 0b1111_0000]);
-        assert_eq!([0b1].bit_shr(1), true);
+        assert!([0b1].bit_shr(1));
+        // a whole byte shifted out
+        assert!([0x00, 0x01].bit_shr(8));
+        assert!(![0x01, 0x00].bit_shr(8));
     }
 
     #[test]
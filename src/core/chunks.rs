@@ -0,0 +1,172 @@
+use super::BitField;
+use crate::{assert_overflow, Error};
+
+/// Padding policy of the last partial chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChunkPadding {
+    /// Tail padding zero bits
+    #[default]
+    ZeroPad,
+    /// Tail padding one bits
+    OnePad,
+    /// Drop the last partial chunk
+    Drop,
+    /// Error if there is a partial chunk
+    Error,
+}
+
+/// Iterator over bits grouped by n, see [`BitIterator::bit_chunks`](super::BitIterator::bit_chunks)
+///
+/// Values are taken from a rolling accumulator, the last partial chunk is padded by [`ChunkPadding`].
+#[derive(Debug, Clone)]
+pub struct BitChunks<'a, T> {
+    data: &'a [u8],
+    n: usize,
+    padding: ChunkPadding,
+    // chunk indices `front..back` not yet yielded
+    front: usize,
+    back: usize,
+    // bits loaded from `data[..next_byte]` but not yet taken, the lowest `acc_len` bits of `acc`
+    acc: u128,
+    acc_len: usize,
+    next_byte: usize,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, T> BitChunks<'a, T>
+where
+    T: TryFrom<u128> + Default,
+{
+    pub(crate) fn new(data: &'a [u8], n: usize, padding: ChunkPadding) -> Result<Self, Error> {
+        let valid_size = (core::mem::size_of::<T>() * 8).min(u128::BITS as usize);
+        assert_overflow!(n, 1, valid_size, "bit_chunks");
+
+        let bits = data.len() * 8;
+        let back = match padding {
+            ChunkPadding::Error if !bits.is_multiple_of(n) => {
+                return Err(Error::PartialChunk {
+                    bits: bits % n,
+                    width: n,
+                })
+            }
+            ChunkPadding::Drop => bits / n,
+            _ => bits.div_ceil(n),
+        };
+        Ok(BitChunks {
+            data,
+            n,
+            padding,
+            front: 0,
+            back,
+            acc: 0,
+            acc_len: 0,
+            next_byte: 0,
+            _marker: core::marker::PhantomData,
+        })
+    }
+
+    /// byte value to pad after the data
+    #[inline(always)]
+    fn pad_byte(&self) -> u8 {
+        match self.padding {
+            ChunkPadding::OnePad => 0xff,
+            _ => 0,
+        }
+    }
+
+    /// take n bits from the accumulator, 1 <= n <= 128
+    fn take(&mut self, n: usize) -> u128 {
+        if n > u64::BITS as usize {
+            let high = self.take_word(n - u64::BITS as usize);
+            (high << u64::BITS) | self.take_word(u64::BITS as usize)
+        } else {
+            self.take_word(n)
+        }
+    }
+
+    /// take n bits from the accumulator, 1 <= n <= 64
+    fn take_word(&mut self, n: usize) -> u128 {
+        while self.acc_len < n {
+            let byte = match self.data.get(self.next_byte) {
+                Some(&byte) => byte,
+                None => self.pad_byte(),
+            };
+            self.acc = (self.acc << 8) | byte as u128;
+            self.acc_len += 8;
+            self.next_byte += 1;
+        }
+        self.acc_len -= n;
+        let value = self.acc >> self.acc_len;
+        self.acc &= !(u128::MAX << self.acc_len);
+        value
+    }
+
+    /// extract the chunk at index directly, without the accumulator
+    fn chunk_at(&self, index: usize) -> u128 {
+        let (pos, bits) = (index * self.n, self.data.len() * 8);
+        let width = self.n.min(bits - pos);
+        let value = self.data.bit_be_field(pos, width).unwrap_or_default() << (self.n - width);
+        match self.padding {
+            ChunkPadding::OnePad if width < self.n => value | !(u128::MAX << (self.n - width)),
+            _ => value,
+        }
+    }
+}
+
+impl<T> Iterator for BitChunks<'_, T>
+where
+    T: TryFrom<u128> + Default,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        let value = self.take(self.n);
+        Some(value.try_into().unwrap_or_default())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back.saturating_sub(self.front);
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, k: usize) -> Option<Self::Item> {
+        if k >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+        if k > 0 {
+            // reload the accumulator at the new position
+            self.front += k;
+            let pos = self.front * self.n;
+            self.next_byte = pos / 8;
+            (self.acc, self.acc_len) = (0, 0);
+            self.take_word(pos % 8);
+        }
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for BitChunks<'_, T>
+where
+    T: TryFrom<u128> + Default,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let value = self.chunk_at(self.back);
+        Some(value.try_into().unwrap_or_default())
+    }
+}
+
+impl<T> ExactSizeIterator for BitChunks<'_, T> where T: TryFrom<u128> + Default {}
+
+impl<T> core::iter::FusedIterator for BitChunks<'_, T> where T: TryFrom<u128> + Default {}
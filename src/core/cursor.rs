@@ -0,0 +1,167 @@
+use super::BitField;
+use crate::{assert_overflow, Error};
+
+/**
+ * Sequential bit source, the highest bit of each byte first
+ */
+pub trait BitRead {
+    type Error: From<Error>;
+
+    /// Read one bit
+    fn read_bit(&mut self) -> Result<bool, Self::Error>;
+
+    /// Read n bits as an unsigned value, 0 <= n <= 64
+    fn read_bits(&mut self, n: usize) -> Result<u64, Self::Error> {
+        assert_overflow!(n, 0, u64::BITS, "read_bits");
+        (0..n).try_fold(0, |acc, _| Ok((acc << 1) | self.read_bit()? as u64))
+    }
+}
+
+/**
+ * Sequential bit sink, the highest bit of each byte first
+ */
+pub trait BitWrite {
+    type Error: From<Error>;
+
+    /// Write one bit
+    fn write_bit(&mut self, bit: bool) -> Result<(), Self::Error>;
+
+    /// Write the n bits of an unsigned value, 0 <= n <= 64
+    fn write_bits(&mut self, value: u64, n: usize) -> Result<(), Self::Error> {
+        assert_overflow!(n, 0, u64::BITS, "write_bits");
+        if n < u64::BITS as usize && value >> n != 0 {
+            let value = value as u128;
+            return Err(Error::ValueOverflow { value, width: n }.into());
+        }
+        (0..n)
+            .rev()
+            .try_for_each(|i| self.write_bit(value >> i & 1 == 1))
+    }
+}
+
+/// Bit reading cursor over a byte buffer
+/// # Examples
+/// ```
+/// # use nbits::core::{BitCursor, BitRead};
+/// let mut cursor = BitCursor::new(&[0b1010_0000, 0b0110_1001]);
+/// assert_eq!(cursor.read_bits(3), Ok(0b101));
+/// assert_eq!(cursor.read_bits(13), Ok(0b0_0000_0110_1001));
+/// assert!(cursor.read_bit().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitCursor<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitCursor<'a> {
+    /// Creates a new cursor at the first bit.
+    pub fn new(data: &'a [u8]) -> Self {
+        BitCursor { data, position: 0 }
+    }
+
+    /// Number of bits read
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of bits not yet read
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    /// Gets the underlying buffer.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl BitRead for BitCursor<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_bit(&mut self) -> Result<bool, Error> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    fn read_bits(&mut self, n: usize) -> Result<u64, Error> {
+        assert_overflow!(n, 0, u64::BITS, "read_bits");
+        if n == 0 {
+            return Ok(0);
+        }
+        let value = self.data.bit_be_field(self.position, n)?;
+        self.position += n;
+        Ok(value as u64)
+    }
+}
+
+/// Bit writing cursor over a byte buffer
+/// # Examples
+/// ```
+/// # use nbits::core::{BitCursorMut, BitWrite};
+/// let mut data = [0_u8; 2];
+/// let mut cursor = BitCursorMut::new(&mut data);
+/// assert_eq!(cursor.write_bits(0b101, 3), Ok(()));
+/// assert_eq!(cursor.write_bits(0b0_0000_0110_1001, 13), Ok(()));
+/// assert!(cursor.write_bit(true).is_err());
+/// assert_eq!(data, [0b1010_0000, 0b0110_1001]);
+/// ```
+#[derive(Debug)]
+pub struct BitCursorMut<'a> {
+    data: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> BitCursorMut<'a> {
+    /// Creates a new cursor at the first bit.
+    pub fn new(data: &'a mut [u8]) -> Self {
+        BitCursorMut { data, position: 0 }
+    }
+
+    /// Number of bits written
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of bits not yet written
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    /// Gets the underlying buffer.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+impl BitWrite for BitCursorMut<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn write_bit(&mut self, bit: bool) -> Result<(), Error> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    fn write_bits(&mut self, value: u64, n: usize) -> Result<(), Error> {
+        assert_overflow!(n, 0, u64::BITS, "write_bits");
+        if n == 0 {
+            return match value {
+                0 => Ok(()),
+                _ => Err(Error::ValueOverflow {
+                    value: value as u128,
+                    width: 0,
+                }),
+            };
+        }
+        self.data
+            .bit_be_set_field(self.position, n, value as u128)?;
+        self.position += n;
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use crate::Error;
+
+/// Maximum width of a bit field
+const FIELD_MAX_BITS: usize = u128::BITS as usize;
+
+/**
+ * Bit field operations implementation for `[u8]`
+ *
+ * Big-endian fields count `offset` from the highest bit of the first byte,
+ * and the first bit of the field is its most significant bit.
+ * Little-endian fields count `offset` from the lowest bit of the first byte,
+ * and the first bit of the field is its least significant bit.
+ */
+pub trait BitField {
+    /// Extract big-endian bit field
+    /// # Parameters
+    /// - `offset`: the position of the field's first bit
+    /// - `width`: the number of bits in the field, 1 <= width <= 128
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitField;
+    /// assert_eq!([0b1110_1011, 0b0110_0000].bit_be_field(3, 7), Ok(0b010_1101));
+    /// assert_eq!([0x12, 0x34, 0x56].bit_be_field(4, 16), Ok(0x2345));
+    /// ```
+    fn bit_be_field(&self, offset: usize, width: usize) -> Result<u128, Error>;
+
+    /// Extract little-endian bit field
+    /// # Parameters
+    /// - `offset`: the position of the field's first bit
+    /// - `width`: the number of bits in the field, 1 <= width <= 128
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitField;
+    /// assert_eq!([0x34, 0x12].bit_le_field(0, 16), Ok(0x1234));
+    /// assert_eq!([0b1110_1011, 0b0110_0000].bit_le_field(3, 7), Ok(0b001_1101));
+    /// ```
+    fn bit_le_field(&self, offset: usize, width: usize) -> Result<u128, Error>;
+
+    /// Insert big-endian bit field
+    /// # Parameters
+    /// - `offset`: the position of the field's first bit
+    /// - `width`: the number of bits in the field, 1 <= width <= 128
+    /// - `value`: the field value, must fit in `width` bits
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitField;
+    /// let mut data = [0_u8; 3];
+    /// assert_eq!(data.bit_be_set_field(4, 16, 0x2345), Ok(()));
+    /// assert_eq!(data, [0x02, 0x34, 0x50]);
+    /// assert!(data.bit_be_set_field(4, 4, 0x10).is_err());
+    /// ```
+    fn bit_be_set_field(&mut self, offset: usize, width: usize, value: u128) -> Result<(), Error>;
+
+    /// Insert little-endian bit field
+    /// # Parameters
+    /// - `offset`: the position of the field's first bit
+    /// - `width`: the number of bits in the field, 1 <= width <= 128
+    /// - `value`: the field value, must fit in `width` bits
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitField;
+    /// let mut data = [0_u8; 3];
+    /// assert_eq!(data.bit_le_set_field(4, 16, 0x2345), Ok(()));
+    /// assert_eq!(data, [0x50, 0x34, 0x02]);
+    /// ```
+    fn bit_le_set_field(&mut self, offset: usize, width: usize, value: u128) -> Result<(), Error>;
+
+    /// Copy `len` bits at `src_offset` in `src` to `dst_offset` in self, a word at a time
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitField;
+    /// let mut data = [0_u8; 3];
+    /// assert_eq!(data.bit_copy_from(4, &[0x12, 0x34], 4, 12), Ok(()));
+    /// assert_eq!(data, [0x02, 0x34, 0x00]);
+    /// ```
+    fn bit_copy_from(
+        &mut self,
+        dst_offset: usize,
+        src: &[u8],
+        src_offset: usize,
+        len: usize,
+    ) -> Result<(), Error>;
+
+    /// Copy `len` bits at `src_offset` to `dst_offset` in self, a word at a time, the two ranges may overlap
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitField;
+    /// let mut data = [0x12, 0x34, 0x00];
+    /// assert_eq!(data.bit_copy_within(4, 0, 16), Ok(()));
+    /// assert_eq!(data, [0x11, 0x23, 0x40]);
+    /// ```
+    fn bit_copy_within(
+        &mut self,
+        dst_offset: usize,
+        src_offset: usize,
+        len: usize,
+    ) -> Result<(), Error>;
+}
+
+impl BitField for [u8] {
+    fn bit_be_field(&self, offset: usize, width: usize) -> Result<u128, Error> {
+        check_field(self, offset, width)?;
+        let (mut pos, end) = (offset, offset + width);
+        let mut value = 0_u128;
+        while pos < end {
+            let (i, skip) = (pos / 8, pos % 8);
+            let take = (8 - skip).min(end - pos);
+            let bits = (self[i] << skip) >> (8 - take);
+            value = (value << take) | bits as u128;
+            pos += take;
+        }
+        Ok(value)
+    }
+
+    fn bit_le_field(&self, offset: usize, width: usize) -> Result<u128, Error> {
+        check_field(self, offset, width)?;
+        let (mut pos, end) = (offset, offset + width);
+        let mut value = 0_u128;
+        while pos < end {
+            let (i, skip) = (pos / 8, pos % 8);
+            let take = (8 - skip).min(end - pos);
+            let bits = (self[i] >> skip) & low_mask(take);
+            value |= (bits as u128) << (pos - offset);
+            pos += take;
+        }
+        Ok(value)
+    }
+
+    fn bit_be_set_field(&mut self, offset: usize, width: usize, value: u128) -> Result<(), Error> {
+        check_field(self, offset, width)?;
+        check_value(value, width)?;
+        let (mut pos, end) = (offset, offset + width);
+        while pos < end {
+            let (i, skip) = (pos / 8, pos % 8);
+            let take = (8 - skip).min(end - pos);
+            let shift = 8 - skip - take;
+            let bits = (value >> (end - pos - take)) as u8 & low_mask(take);
+            self[i] = (self[i] & !(low_mask(take) << shift)) | (bits << shift);
+            pos += take;
+        }
+        Ok(())
+    }
+
+    fn bit_le_set_field(&mut self, offset: usize, width: usize, value: u128) -> Result<(), Error> {
+        check_field(self, offset, width)?;
+        check_value(value, width)?;
+        let (mut pos, end) = (offset, offset + width);
+        while pos < end {
+            let (i, skip) = (pos / 8, pos % 8);
+            let take = (8 - skip).min(end - pos);
+            let bits = (value >> (pos - offset)) as u8 & low_mask(take);
+            self[i] = (self[i] & !(low_mask(take) << skip)) | (bits << skip);
+            pos += take;
+        }
+        Ok(())
+    }
+
+    fn bit_copy_from(
+        &mut self,
+        dst_offset: usize,
+        src: &[u8],
+        src_offset: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        check_range(src, src_offset, len)?;
+        check_range(self, dst_offset, len)?;
+        copy_words(dst_offset, len).try_for_each(|(pos, width)| {
+            let word = load_word(src, src_offset + pos, width);
+            store_word(self, dst_offset + pos, word, width)
+        })
+    }
+
+    fn bit_copy_within(
+        &mut self,
+        dst_offset: usize,
+        src_offset: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        check_range(self, src_offset, len)?;
+        check_range(self, dst_offset, len)?;
+        let mut words = copy_words(dst_offset, len);
+        let mut copy = |(pos, width): (usize, usize)| {
+            let word = load_word(self, src_offset + pos, width);
+            store_word(self, dst_offset + pos, word, width)
+        };
+        // copy backward if destination is after source, so overlapped bits are read before written
+        match dst_offset > src_offset {
+            true => words.rev().try_for_each(&mut copy),
+            false => words.try_for_each(&mut copy),
+        }
+    }
+}
+
+/// Bits copied at a time
+const COPY_WORD_BITS: usize = u64::BITS as usize;
+
+/// Split `len` bits copied to `dest` into `(pos, width)` words: the bits
+/// before the first byte boundary of the destination, whole 64-bit words
+/// from there, and the remaining bits.
+fn copy_words(dest: usize, len: usize) -> impl DoubleEndedIterator<Item = (usize, usize)> {
+    let head = ((8 - dest % 8) % 8).min(len);
+    core::iter::once((0, head))
+        .chain(
+            (head..len)
+                .step_by(COPY_WORD_BITS)
+                .map(move |pos| (pos, COPY_WORD_BITS.min(len - pos))),
+        )
+        .filter(|&(_, width)| width > 0)
+}
+
+/// Load `width` bits at `offset` as the highest bits of a word, 1 <= width <= 64
+#[inline]
+fn load_word(data: &[u8], offset: usize, width: usize) -> u64 {
+    // at most 9 bytes hold 64 bits which don't start on a byte boundary
+    let (i, end) = (offset / 8, (offset + width).div_ceil(8));
+    let mut bytes = [0; 16];
+    bytes[..end - i].copy_from_slice(&data[i..end]);
+    ((u128::from_be_bytes(bytes) << (offset % 8)) >> 64) as u64
+}
+
+/// Store the highest `width` bits of `word` at `offset`, 1 <= width <= 64,
+/// whole bytes are stored directly where `offset` is on a byte boundary
+#[inline]
+fn store_word(data: &mut [u8], offset: usize, word: u64, width: usize) -> Result<(), Error> {
+    let whole = match offset % 8 {
+        0 => width / 8,
+        _ => 0,
+    };
+    let i = offset / 8;
+    data[i..i + whole].copy_from_slice(&word.to_be_bytes()[..whole]);
+
+    match width - whole * 8 {
+        0 => Ok(()),
+        rest => {
+            let bits = (word << (whole * 8)) >> (COPY_WORD_BITS - rest);
+            data.bit_be_set_field(offset + whole * 8, rest, bits as u128)
+        }
+    }
+}
+
+/// The lowest n bits mask of a byte, 1 <= n <= 8
+#[inline(always)]
+const fn low_mask(n: usize) -> u8 {
+    0xff >> (8 - n)
+}
+
+/// Check field range in the buffer
+#[inline]
+fn check_field(data: &[u8], offset: usize, width: usize) -> Result<(), Error> {
+    if !(1..=FIELD_MAX_BITS).contains(&width) {
+        return Err(Error::InvalidWidth {
+            width,
+            max: FIELD_MAX_BITS,
+        });
+    }
+    check_range(data, offset, width)
+}
+
+/// Check bit range in the buffer, empty range is allowed
+#[inline]
+fn check_range(data: &[u8], offset: usize, len: usize) -> Result<(), Error> {
+    let bits = data.len() * 8;
+    match offset.checked_add(len) {
+        Some(end) if end <= bits => Ok(()),
+        _ => Err(Error::OutOfBounds { offset, len, bits }),
+    }
+}
+
+/// Check value fits in the field width
+#[inline]
+fn check_value(value: u128, width: usize) -> Result<(), Error> {
+    match width < u128::BITS as usize && value >> width != 0 {
+        true => Err(Error::ValueOverflow { value, width }),
+        false => Ok(()),
+    }
+}
@@ -1,4 +1,7 @@
-use crate::assert_overflow;
+use super::{BitChunks, Bitwise, ChunkPadding};
+use crate::{assert_overflow, Error};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
 
 /**
  * Bits iterator implementation on `[u8]`
@@ -20,7 +23,7 @@ pub trait BitIterator {
     /// # Parameters  
     /// - `T`: the type to contains the grouped bits  
     /// - `n`: the number of bits to group  
-    /// - 1 <= n <= T::BITS <= 32
+    /// - 1 <= n <= T::BITS <= 128
     ///   
     /// # Examples  
     /// ```
@@ -34,9 +37,87 @@ pub trait BitIterator {
     ///     vec![0b111_1111_1111, 0b111_1111_1111, 0b110_0000_0000]
     /// );
     /// ```
-    fn bit_chunks<T>(&self, n: usize) -> impl Iterator<Item = T>
+    fn bit_chunks<T>(&self, n: usize) -> BitChunks<'_, T>
     where
-        T: TryFrom<u64> + Default;
+        T: TryFrom<u128> + Default;
+
+    /// Returns the bits in the buffer grouped by n, with padding policy of the last partial chunk
+    ///
+    /// # Parameters
+    /// - `T`: the type to contains the grouped bits
+    /// - `n`: the number of bits to group
+    /// - 1 <= n <= T::BITS <= 128
+    /// - `padding`: padding policy of the last partial chunk
+    ///
+    /// # Returns
+    /// - `Error::PartialChunk` if there is a partial chunk with `ChunkPadding::Error`
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::core::{BitIterator, ChunkPadding};
+    /// let data = [0b1111_0000, 0b1111_0000];
+    /// let chunks = |padding| data.bit_chunks_with(6, padding).map(|vs| vs.collect::<Vec<u8>>());
+    /// assert_eq!(chunks(ChunkPadding::ZeroPad), Ok(vec![0b11_1100, 0b00_1111, 0b00_0000]));
+    /// assert_eq!(chunks(ChunkPadding::OnePad), Ok(vec![0b11_1100, 0b00_1111, 0b00_0011]));
+    /// assert_eq!(chunks(ChunkPadding::Drop), Ok(vec![0b11_1100, 0b00_1111]));
+    /// assert!(chunks(ChunkPadding::Error).is_err());
+    /// ```
+    fn bit_chunks_with<T>(
+        &self,
+        n: usize,
+        padding: ChunkPadding,
+    ) -> Result<BitChunks<'_, T>, Error>
+    where
+        T: TryFrom<u128> + Default;
+
+    /// Returns the bits in the buffer grouped by n, without lossy conversion
+    ///
+    /// # Parameters
+    /// - `T`: the type to contains the grouped bits
+    /// - `n`: the number of bits to group
+    /// - 1 <= n <= 128
+    ///
+    /// # Returns
+    /// - `Error::ChunkOverflow` for a chunk value which `T` can't hold
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitIterator;
+    /// # use nbits::Error;
+    /// assert_eq!(
+    ///     [0b0111_1111, 0b1000_0000].try_bit_chunks::<i8>(8).collect::<Vec<_>>(),
+    ///     vec![Ok(0b0111_1111), Err(Error::ChunkOverflow { index: 1, value: 0b1000_0000, width: 7 })]
+    /// );
+    /// ```
+    fn try_bit_chunks<T>(&self, n: usize) -> impl Iterator<Item = Result<T, Error>>
+    where
+        T: TryFrom<u128>;
+
+    /// Iterator positions of `1` bits
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitIterator;
+    /// assert_eq!(
+    ///     [0b1001_0000_u8, 0b0000_0001].bit_iter_ones().collect::<Vec<usize>>(),
+    ///     vec![0, 3, 15]
+    /// );
+    /// assert_eq!(
+    ///     [0b1001_0000_u8, 0b0000_0001].bit_iter_ones().rev().collect::<Vec<usize>>(),
+    ///     vec![15, 3, 0]
+    /// );
+    /// ```
+    fn bit_iter_ones(&self) -> BitPositions<'_>;
+
+    /// Iterator positions of `0` bits
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitIterator;
+    /// assert_eq!(
+    ///     [0b1110_1111_u8, 0b1111_1110].bit_iter_zeros().collect::<Vec<usize>>(),
+    ///     vec![3, 15]
+    /// );
+    /// ```
+    fn bit_iter_zeros(&self) -> BitPositions<'_>;
 }
 
 impl BitIterator for [u8] {
@@ -45,32 +126,127 @@ impl BitIterator for [u8] {
             .flat_map(|&v| (0_u8..8).rev().map(move |n| (v & (1 << n)) != 0))
     }
 
-    fn bit_chunks<T>(&self, n: usize) -> impl Iterator<Item = T>
+    #[inline]
+    fn bit_chunks<T>(&self, n: usize) -> BitChunks<'_, T>
     where
-        T: TryFrom<u64> + Default,
+        T: TryFrom<u128> + Default,
     {
-        let valid_size = (std::mem::size_of::<T>() * 8).min(32);
-        assert_overflow!(n, 1, valid_size, "bit_chunks");
+        BitChunks::new(self, n, ChunkPadding::ZeroPad).unwrap()
+    }
 
-        // enumerate bytes window of 64 bits width, split item values from those windows
-        let bit_mask: u64 = (0..n).fold(0, |acc, v| acc | (1 << v));
-        let mut bit_pos = 0;
-        (0..self.len()).flat_map(move |i| {
-            let window_value = self.byte_window_64(i);
-            let window_end = i * 8 + u64::BITS as usize; // current bit window end
-            debug_assert!(matches!(window_end - bit_pos, 1..=64)); // current bit window size
+    #[inline]
+    fn bit_chunks_with<T>(&self, n: usize, padding: ChunkPadding) -> Result<BitChunks<'_, T>, Error>
+    where
+        T: TryFrom<u128> + Default,
+    {
+        BitChunks::new(self, n, padding)
+    }
 
-            let mut vs = vec![];
-            while (bit_pos + n) <= window_end && (bit_pos + n) < self.len() * 8 + n {
-                bit_pos += n;
-                let value = (window_value >> (window_end - bit_pos)) & bit_mask;
-                vs.push(value.try_into().unwrap_or_default());
-            }
-            vs
-        })
+    fn try_bit_chunks<T>(&self, n: usize) -> impl Iterator<Item = Result<T, Error>>
+    where
+        T: TryFrom<u128>,
+    {
+        self.bit_chunks::<u128>(n)
+            .enumerate()
+            .map(|(index, value)| {
+                value.try_into().map_err(|_| Error::ChunkOverflow {
+                    index,
+                    value,
+                    width: value_width::<T>(),
+                })
+            })
     }
+
+    #[inline]
+    fn bit_iter_ones(&self) -> BitPositions<'_> {
+        BitPositions::new(self, true)
+    }
+
+    #[inline]
+    fn bit_iter_zeros(&self) -> BitPositions<'_> {
+        BitPositions::new(self, false)
+    }
+}
+
+/// The number of low bits whose every value `T` holds, 7 for `i8`
+fn value_width<T: TryFrom<u128>>() -> usize {
+    (1..=u128::BITS)
+        .take_while(|&n| T::try_from(u128::MAX >> (u128::BITS - n)).is_ok())
+        .count()
+}
+
+/// Iterator over positions of bits equal to `1` or `0`
+///
+/// Searching skips whole words which contain no wanted bit,
+/// so sparse buffers are iterated in a few steps.
+#[derive(Debug, Clone)]
+pub struct BitPositions<'a> {
+    data: &'a [u8],
+    bit: bool,
+    front: usize,
+    back: usize,
+    remaining: usize,
 }
 
+impl<'a> BitPositions<'a> {
+    fn new(data: &'a [u8], bit: bool) -> Self {
+        let remaining = match bit {
+            true => data.bit_count_ones(),
+            false => data.bit_count_zeros(),
+        };
+        BitPositions {
+            data,
+            bit,
+            front: 0,
+            back: data.len() * 8,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for BitPositions<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let pos = match self.bit {
+            true => self.data.bit_next_one(self.front),
+            false => self.data.bit_next_zero(self.front),
+        }?;
+        debug_assert!(pos < self.back);
+        self.front = pos + 1;
+        self.remaining -= 1;
+        Some(pos)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for BitPositions<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let pos = match self.bit {
+            true => self.data.bit_prev_one(self.back - 1),
+            false => self.data.bit_prev_zero(self.back - 1),
+        }?;
+        debug_assert!(pos >= self.front);
+        self.back = pos;
+        self.remaining -= 1;
+        Some(pos)
+    }
+}
+
+impl ExactSizeIterator for BitPositions<'_> {}
+
+impl core::iter::FusedIterator for BitPositions<'_> {}
+
 pub trait FromBits {
     /// Convert enumerated bool values to buffer
     /// # Examples
@@ -88,7 +264,7 @@ pub trait FromBits {
     ///
     /// # Parameters
     /// - `n`: the number of bits to conjoin
-    /// - 1 <= n <= 32
+    /// - 1 <= n <= 128
     /// - if n > T::BITS, left padding zero bits
     ///
     /// # Examples
@@ -105,10 +281,83 @@ pub trait FromBits {
     /// ```
     fn from_bits_chunk<T, U>(chunks: U, n: usize) -> Self
     where
-        T: TryInto<u64>,
+        T: TryInto<u128>,
         U: Iterator<Item = T>;
+
+    /// Conjoin the n bits of each value, without lossy conversion
+    ///
+    /// # Parameters
+    /// - `n`: the number of bits to conjoin
+    /// - 1 <= n <= 128
+    ///
+    /// # Returns
+    /// - `Error::ChunkOverflow` for the first value which doesn't fit in n bits
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::{Error, FromBits};
+    /// assert_eq!(
+    ///     Vec::try_from_bits_chunk([0b111_1111_1111_u16, 0b1111].into_iter(), 11),
+    ///     Ok(vec![0b1111_1111, 0b1110_0000, 0b0011_1100])
+    /// );
+    /// assert_eq!(
+    ///     Vec::try_from_bits_chunk([0b1111_u16, 2048].into_iter(), 11),
+    ///     Err(Error::ChunkOverflow { index: 1, value: 2048, width: 11 })
+    /// );
+    /// ```
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+        Self: Sized;
+
+    /// Conjoin the n bits of each value, with policy of the trailing bits of a partial byte
+    ///
+    /// # Parameters
+    /// - `n`: the number of bits to conjoin
+    /// - 1 <= n <= 128
+    /// - `trailing`: policy of the trailing bits which don't fill a byte
+    ///
+    /// # Returns
+    /// - `Error::ChunkOverflow` for the first value which doesn't fit in n bits
+    /// - `Error::NonZeroTrailing` if trailing bits are not zero with `TrailingBits::RequireZero`
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::FromBits;
+    /// # use nbits::core::TrailingBits;
+    /// // base64 "/w==" decodes to one byte, the trailing 4 bits must be zero
+    /// let indices = [0b11_1111_u8, 0b11_0000];
+    /// let data = |trailing| Vec::from_bits_chunk_with(indices.into_iter(), 6, trailing);
+    /// assert_eq!(data(TrailingBits::Keep), Ok(vec![0b1111_1111, 0b0000_0000]));
+    /// assert_eq!(data(TrailingBits::Drop), Ok(vec![0b1111_1111]));
+    /// assert_eq!(data(TrailingBits::RequireZero), Ok(vec![0b1111_1111]));
+    /// assert!(Vec::from_bits_chunk_with([0b11_1111_u8, 0b11_0001].into_iter(), 6, TrailingBits::RequireZero).is_err());
+    /// ```
+    fn from_bits_chunk_with<T, U>(
+        chunks: U,
+        n: usize,
+        trailing: TrailingBits,
+    ) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+        Self: Sized;
 }
 
+/// Policy of the trailing bits which don't fill a byte when conjoining
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingBits {
+    /// Keep the trailing bits as a byte tail padding zero
+    #[default]
+    Keep,
+    /// Drop the trailing bits
+    Drop,
+    /// Drop the trailing bits, which must be zero
+    RequireZero,
+}
+
+#[cfg(feature = "alloc")]
 impl FromBits for Vec<u8> {
     fn from_bits<U>(bits: U) -> Self
     where
@@ -123,7 +372,7 @@ impl FromBits for Vec<u8> {
                     v |= 1 << n;
                 }
                 match n {
-                    0 => Some(std::mem::take(&mut v)),
+                    0 => Some(core::mem::take(&mut v)),
                     _ => None,
                 }
             })
@@ -132,73 +381,395 @@ impl FromBits for Vec<u8> {
 
     fn from_bits_chunk<T, U>(chunks: U, n: usize) -> Self
     where
-        T: TryInto<u64>,
+        T: TryInto<u128>,
         U: Iterator<Item = T>,
     {
-        assert_overflow!(n, 1, 32, "from_chunks");
-        let bit_mask: u64 = (0..n).fold(0, |acc, v| acc | (1 << v));
+        assert_overflow!(n, 1, u128::BITS, "from_chunks");
+        let bit_mask = u128::MAX >> (u128::BITS as usize - n);
 
-        let mut rem = TinyBits::new(0, 0);
-        let mut vs: Vec<u8> = chunks
+        let mut rem = TinyBits::default();
+        let mut vs = Vec::with_capacity((chunks.size_hint().0 * n).div_ceil(8));
+        let mut out = |byte| vs.push(byte);
+        chunks
             .map(|v| v.try_into().unwrap_or_default())
-            .flat_map(|mut value: u64| {
-                value &= bit_mask;
-                value <<= u64::BITS as usize - n;
-                rem.prefix_to(&mut value);
-
-                let partial = (n + rem.len()) / 8;
-                let bytes = value.to_be_bytes();
-                rem = TinyBits::new(bytes[partial], (n + rem.len()) % 8);
-                bytes[..partial].to_vec()
-            })
-            .collect();
-        vs.extend_from_slice(&rem.value());
+            .for_each(|value: u128| rem.push(value & bit_mask, n, &mut out));
+        vs.extend(rem.value());
         vs
     }
+
+    #[inline]
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        Self::from_bits_chunk_with(chunks, n, TrailingBits::Keep)
+    }
+
+    fn from_bits_chunk_with<T, U>(
+        chunks: U,
+        n: usize,
+        trailing: TrailingBits,
+    ) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        assert_overflow!(n, 1, u128::BITS, "from_chunks_with");
+        let mut vs = Vec::with_capacity((chunks.size_hint().0 * n).div_ceil(8));
+        let rem = conjoin(chunks, n, usize::MAX, |byte| vs.push(byte))?;
+        match trailing {
+            TrailingBits::Keep => vs.extend(rem.value()),
+            TrailingBits::RequireZero if rem.data != 0 => {
+                return Err(Error::NonZeroTrailing { bits: rem.len });
+            }
+            _ => (),
+        }
+        Ok(vs)
+    }
 }
 
-trait ByteWindow {
-    // get window value from bytes. If insufficient, tail padding zero
-    fn byte_window_64(&self, byte_index: usize) -> u64;
+#[cfg(feature = "alloc")]
+impl FromBits for Box<[u8]> {
+    #[inline]
+    fn from_bits<U>(bits: U) -> Self
+    where
+        U: Iterator<Item = bool>,
+    {
+        Vec::from_bits(bits).into_boxed_slice()
+    }
+
+    #[inline]
+    fn from_bits_chunk<T, U>(chunks: U, n: usize) -> Self
+    where
+        T: TryInto<u128>,
+        U: Iterator<Item = T>,
+    {
+        Vec::from_bits_chunk(chunks, n).into_boxed_slice()
+    }
+
+    #[inline]
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        Vec::try_from_bits_chunk(chunks, n).map(Vec::into_boxed_slice)
+    }
+
+    #[inline]
+    fn from_bits_chunk_with<T, U>(
+        chunks: U,
+        n: usize,
+        trailing: TrailingBits,
+    ) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        Vec::from_bits_chunk_with(chunks, n, trailing).map(Vec::into_boxed_slice)
+    }
+}
+
+/**
+ * Conversion from bits into fixed-size buffers, which the bits must fill exactly
+ *
+ * The number of bytes is counted as `FromBits` does, a partial byte is tail padded zero.
+ */
+pub trait TryFromBits: Sized {
+    /// Convert enumerated bool values to a fixed-size buffer
+    ///
+    /// # Returns
+    /// - `Error::LengthMismatch` if the bits don't fill exactly the buffer
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::{Error, TryFromBits};
+    /// let bits = [true, true, true, true, false, false, false, false, true];
+    /// assert_eq!(<[u8; 2]>::try_from_bits(bits.into_iter()), Ok([0b1111_0000, 0b1000_0000]));
+    /// assert_eq!(
+    ///     <[u8; 1]>::try_from_bits(bits.into_iter()),
+    ///     Err(Error::LengthMismatch { expected: 1, found: 2 })
+    /// );
+    /// ```
+    fn try_from_bits<U>(bits: U) -> Result<Self, Error>
+    where
+        U: Iterator<Item = bool>;
+
+    /// Conjoin the n bits of each value into a fixed-size buffer, without lossy conversion
+    ///
+    /// # Parameters
+    /// - `n`: the number of bits to conjoin
+    /// - 1 <= n <= 128
+    ///
+    /// # Returns
+    /// - `Error::ChunkOverflow` for the first value which doesn't fit in n bits
+    /// - `Error::LengthMismatch` if the bits don't fill exactly the buffer
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::{Error, TryFromBits};
+    /// assert_eq!(
+    ///     <[u8; 3]>::try_from_bits_chunk([0b111_1111_1111_u16, 0b1111].into_iter(), 11),
+    ///     Ok([0b1111_1111, 0b1110_0000, 0b0011_1100])
+    /// );
+    /// assert_eq!(
+    ///     <[u8; 4]>::try_from_bits_chunk([0b111_1111_1111_u16, 0b1111].into_iter(), 11),
+    ///     Err(Error::LengthMismatch { expected: 4, found: 3 })
+    /// );
+    /// ```
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>;
+}
+
+impl<const N: usize> TryFromBits for [u8; N] {
+    #[inline]
+    fn try_from_bits<U>(bits: U) -> Result<Self, Error>
+    where
+        U: Iterator<Item = bool>,
+    {
+        Self::try_from_bits_chunk(bits.map(u8::from), 1)
+    }
+
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        assert_overflow!(n, 1, u128::BITS, "try_from_bits_chunk");
+        let (mut buf, mut len) = ([0; N], 0);
+        // count bytes beyond the buffer for the error
+        let mut out = |byte| {
+            if let Some(slot) = buf.get_mut(len) {
+                *slot = byte;
+            }
+            len += 1;
+        };
+        let rem = conjoin(chunks, n, usize::MAX, &mut out)?;
+        rem.value().into_iter().for_each(out);
+        match len == N {
+            true => Ok(buf),
+            false => Err(Error::LengthMismatch {
+                expected: N,
+                found: len,
+            }),
+        }
+    }
+}
+
+/**
+ * Conversion from bits into a caller-provided `[u8]`, the into-slice counterpart of `FromBits`
+ *
+ * Bits are written from the first bit, a partial byte is tail padded zero,
+ * and bytes after the written bits are untouched.
+ */
+pub trait BitFill {
+    /// Write enumerated bool values, return the number of bits written
+    ///
+    /// # Returns
+    /// - `Error::OutOfBounds` if the bits don't fit in the buffer
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitFill;
+    /// let mut data = [0xff; 3];
+    /// let bits = [true, true, true, true, false, false, false, false, true];
+    /// assert_eq!(data.bit_fill(bits.into_iter()), Ok(9));
+    /// assert_eq!(data, [0b1111_0000, 0b1000_0000, 0xff]);
+    /// assert!(data[..1].bit_fill(bits.into_iter()).is_err());
+    /// ```
+    fn bit_fill<U>(&mut self, bits: U) -> Result<usize, Error>
+    where
+        U: Iterator<Item = bool>;
+
+    /// Conjoin the n bits of each value, return the number of bits written
+    ///
+    /// # Parameters
+    /// - `n`: the number of bits to conjoin
+    /// - 1 <= n <= 128
+    ///
+    /// # Returns
+    /// - `Error::ChunkOverflow` for the first value which doesn't fit in n bits
+    /// - `Error::OutOfBounds` for the first value which doesn't fit in the buffer
+    ///
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitFill;
+    /// let mut data = [0xff; 4];
+    /// assert_eq!(data.bit_fill_chunk([0b111_1111_1111_u16, 0b1111].into_iter(), 11), Ok(22));
+    /// assert_eq!(data, [0b1111_1111, 0b1110_0000, 0b0011_1100, 0xff]);
+    /// ```
+    fn bit_fill_chunk<T, U>(&mut self, chunks: U, n: usize) -> Result<usize, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>;
+}
+
+impl BitFill for [u8] {
+    #[inline]
+    fn bit_fill<U>(&mut self, bits: U) -> Result<usize, Error>
+    where
+        U: Iterator<Item = bool>,
+    {
+        self.bit_fill_chunk(bits.map(u8::from), 1)
+    }
+
+    fn bit_fill_chunk<T, U>(&mut self, chunks: U, n: usize) -> Result<usize, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        assert_overflow!(n, 1, u128::BITS, "bit_fill_chunk");
+        let (bits, mut len) = (self.len() * 8, 0);
+        let mut out = |byte| {
+            self[len] = byte;
+            len += 1;
+        };
+        let rem = conjoin(chunks, n, bits, &mut out)?;
+        rem.value().into_iter().for_each(out);
+        let padding = rem.value().map_or(0, |_| 8 - rem.len);
+        Ok(len * 8 - padding)
+    }
+}
+
+/**
+ * Collect iterators of chunk values into bits, the pipeline form of `FromBits`
+ */
+pub trait CollectBits: Iterator + Sized {
+    /// Conjoin the lowest n bits of each value, as `FromBits::from_bits_chunk`
+    /// # Examples
+    /// ```
+    /// # use nbits::core::CollectBits;
+    /// let indices = [0b111_1111_1111_u16, 0b1111];
+    /// let data: Vec<u8> = indices.iter().copied().collect_bits(11);
+    /// assert_eq!(data, [0b1111_1111, 0b1110_0000, 0b0011_1100]);
+    /// ```
+    #[inline]
+    fn collect_bits<B>(self, n: usize) -> B
+    where
+        B: FromBits,
+        Self::Item: TryInto<u128>,
+    {
+        B::from_bits_chunk(self, n)
+    }
+
+    /// Conjoin the n bits of each value without lossy conversion, as `FromBits::try_from_bits_chunk`
+    /// # Examples
+    /// ```
+    /// # use nbits::core::CollectBits;
+    /// # use nbits::Error;
+    /// let data: Result<Vec<u8>, _> = [0b1111_u16, 2048].into_iter().try_collect_bits(11);
+    /// assert_eq!(data, Err(Error::ChunkOverflow { index: 1, value: 2048, width: 11 }));
+    /// ```
+    #[inline]
+    fn try_collect_bits<B>(self, n: usize) -> Result<B, Error>
+    where
+        B: FromBits,
+        Self::Item: Into<u128>,
+    {
+        B::try_from_bits_chunk(self, n)
+    }
+
+    /// Conjoin the n bits of each value into a fixed-size buffer, as `TryFromBits::try_from_bits_chunk`
+    /// # Examples
+    /// ```
+    /// # use nbits::core::CollectBits;
+    /// let indices = [0b111_1111_1111_u16, 0b1111];
+    /// let data = indices.into_iter().collect_chunks::<3>(11);
+    /// assert_eq!(data, Ok([0b1111_1111, 0b1110_0000, 0b0011_1100]));
+    /// assert!(indices.into_iter().collect_chunks::<2>(11).is_err());
+    /// ```
+    #[inline]
+    fn collect_chunks<const N: usize>(self, n: usize) -> Result<[u8; N], Error>
+    where
+        Self::Item: Into<u128>,
+    {
+        <[u8; N]>::try_from_bits_chunk(self, n)
+    }
 }
 
-impl ByteWindow for [u8] {
-    fn byte_window_64(&self, i: usize) -> u64 {
-        let bytes = self[i..]
-            .iter()
-            .copied()
-            .chain(std::iter::repeat(0))
-            .take(8)
-            .collect::<Vec<u8>>();
-        u64::from_be_bytes(bytes.try_into().unwrap())
+impl<I: Iterator> CollectBits for I {}
+
+/// Conjoin the n bits of each value at most `max` bits, output completed bytes
+/// and return the carried bits
+fn conjoin<T, U>(
+    chunks: U,
+    n: usize,
+    max: usize,
+    mut out: impl FnMut(u8),
+) -> Result<TinyBits, Error>
+where
+    T: Into<u128>,
+    U: Iterator<Item = T>,
+{
+    let mut rem = TinyBits::default();
+    for (index, value) in chunks.map(Into::into).enumerate() {
+        if n < u128::BITS as usize && value >> n != 0 {
+            return Err(Error::ChunkOverflow {
+                index,
+                value,
+                width: n,
+            });
+        }
+        let offset = index.saturating_mul(n);
+        if offset.saturating_add(n) > max {
+            return Err(Error::OutOfBounds {
+                offset,
+                len: n,
+                bits: max,
+            });
+        }
+        rem.push(value, n, &mut out);
     }
+    Ok(rem)
 }
 
-/// 0~7 bits data
-#[derive(Debug)]
-struct TinyBits {
+/// 0~7 bits data, carried between conjoined values,
+/// the accumulator of `FromBits` chunks and `io::BitWriter`
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TinyBits {
     data: u8,
     len: usize,
 }
 
 impl TinyBits {
-    pub fn new(data: u8, len: usize) -> Self {
-        TinyBits { data, len }
+    /// tail padding zero of the carried bits
+    pub fn value(&self) -> Option<u8> {
+        match self.len > 0 {
+            true => Some(self.data << (8 - self.len)),
+            false => None,
+        }
     }
+
+    /// the number of carried bits
+    #[cfg(feature = "std")]
+    #[inline(always)]
     pub fn len(&self) -> usize {
         self.len
     }
-    pub fn value(&self) -> Vec<u8> {
-        match self.len > 0 {
-            true => vec![self.data],
-            false => vec![],
+
+    /// append the lowest n bits of value, output completed bytes
+    pub fn push(&mut self, value: u128, n: usize, out: &mut impl FnMut(u8)) {
+        if n > u64::BITS as usize {
+            self.push_word((value >> u64::BITS) as u64, n - u64::BITS as usize, out);
+            self.push_word(value as u64, u64::BITS as usize, out);
+        } else {
+            self.push_word(value as u64, n, out);
         }
     }
-    /// insert bits as value prefix
-    pub fn prefix_to(&self, value: &mut u64) {
-        if self.len > 0 {
-            *value >>= self.len;
-            *value |= (self.data as u64) << (64 - 8); // u64 left byte
+
+    /// append the lowest n bits of value, 0 <= n <= 64
+    fn push_word(&mut self, value: u64, n: usize, out: &mut impl FnMut(u8)) {
+        // 0~7 carried bits and at most 64 new bits
+        let acc = ((self.data as u128) << n) | value as u128;
+        let mut len = self.len + n;
+        while len >= 8 {
+            len -= 8;
+            out((acc >> len) as u8);
         }
+        self.data = (acc & !(u128::MAX << len)) as u8;
+        self.len = len;
     }
 }
@@ -1,7 +1,17 @@
 mod arith;
 mod bitwise;
+mod chunks;
+mod cursor;
+mod field;
 mod iter;
 
 pub use arith::BitArith;
-pub use bitwise::Bitwise;
-pub use iter::{BitIterator, FromBits};
+pub use bitwise::{Bitwise, LengthPolicy};
+pub use chunks::{BitChunks, ChunkPadding};
+pub use cursor::{BitCursor, BitCursorMut, BitRead, BitWrite};
+pub use field::BitField;
+#[cfg(feature = "std")]
+pub(crate) use iter::TinyBits;
+pub use iter::{
+    BitFill, BitIterator, BitPositions, CollectBits, FromBits, TrailingBits, TryFromBits,
+};
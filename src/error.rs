@@ -0,0 +1,94 @@
+/// Errors of fallible bit operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Bit range `offset..offset + len` exceeds a buffer of `bits` bits
+    OutOfBounds {
+        offset: usize,
+        len: usize,
+        bits: usize,
+    },
+    /// Bit width is not in `1..=max`
+    InvalidWidth { width: usize, max: usize },
+    /// Value does not fit in `width` bits
+    ValueOverflow { value: u128, width: usize },
+    /// Signed value does not fit in `width` bits of two's complement
+    SignedOverflow { value: i128, width: usize },
+    /// Operand of `found` bytes, expected `expected` bytes
+    LengthMismatch { expected: usize, found: usize },
+    /// Operand of `len` bytes loses significant bits when truncated to `max` bytes
+    Truncated { len: usize, max: usize },
+    /// Chunk at `index` has `value` which does not fit in `width` bits
+    ChunkOverflow {
+        index: usize,
+        value: u128,
+        width: usize,
+    },
+    /// Trailing `bits` bits don't fill a chunk of `width` bits
+    PartialChunk { bits: usize, width: usize },
+    /// Trailing `bits` bits which don't fill a byte are not zero
+    NonZeroTrailing { bits: usize },
+    /// Value `value` has no code in the variable-length code
+    Unrepresentable { value: u128 },
+    /// Malformed variable-length code, or its value overflows the decoded type
+    InvalidCode,
+    /// Value of `bits` significant bits does not fit in `width` bits
+    WidthOverflow { bits: usize, width: usize },
+    /// Variable-length encoding is longer than the minimal one of its value
+    NonMinimal,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Error::OutOfBounds { offset, len, bits } => write!(
+                f,
+                "[nbits] bit range `{offset}..{}` out of bounds of `{bits}` bits",
+                offset.saturating_add(len)
+            ),
+            Error::InvalidWidth { width, max } => {
+                write!(f, "[nbits] bit width `{width}` not in `1..={max}`")
+            }
+            Error::ValueOverflow { value, width } => {
+                write!(f, "[nbits] value `{value}` overflow `{width}` bits")
+            }
+            Error::SignedOverflow { value, width } => {
+                write!(f, "[nbits] signed value `{value}` overflow `{width}` bits")
+            }
+            Error::LengthMismatch { expected, found } => {
+                write!(
+                    f,
+                    "[nbits] length `{found}` mismatch, expected `{expected}` bytes"
+                )
+            }
+            Error::Truncated { len, max } => write!(
+                f,
+                "[nbits] operand of `{len}` bytes loses significant bits in `{max}` bytes"
+            ),
+            Error::ChunkOverflow {
+                index,
+                value,
+                width,
+            } => write!(
+                f,
+                "[nbits] chunk `{index}` value `{value}` overflow `{width}` bits"
+            ),
+            Error::PartialChunk { bits, width } => write!(
+                f,
+                "[nbits] trailing `{bits}` bits don't fill a chunk of `{width}` bits"
+            ),
+            Error::NonZeroTrailing { bits } => {
+                write!(f, "[nbits] trailing `{bits}` bits are not zero")
+            }
+            Error::Unrepresentable { value } => {
+                write!(f, "[nbits] value `{value}` can't be encoded")
+            }
+            Error::InvalidCode => write!(f, "[nbits] invalid variable-length code"),
+            Error::WidthOverflow { bits, width } => {
+                write!(f, "[nbits] value of `{bits}` bits overflow `{width}` bits")
+            }
+            Error::NonMinimal => write!(f, "[nbits] non-minimal variable-length encoding"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
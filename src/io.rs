@@ -0,0 +1,479 @@
+//! Streaming bit operations over `std::io`
+
+use crate::core::{BitRead, BitWrite, TinyBits};
+use crate::{assert_overflow, Error};
+use core::ops::Range;
+use std::format;
+use std::io::{self, Read, Write};
+
+/**
+ * `BitReader` reads bits from any `Read` stream, the highest bit of each byte first.
+ *
+ * The stream is read byte by byte, wrap it in a `BufReader` for unbuffered sources.
+ */
+#[derive(Debug)]
+pub struct BitReader<R> {
+    inner: R,
+    // current partially read byte, the lowest `left` bits are unread
+    byte: u8,
+    left: usize,
+    // bytes taken from the stream by a failed read, returned before reading more
+    pending: [u8; 8],
+    unread: Range<usize>,
+    position: u64,
+}
+
+impl<R: Read> BitReader<R> {
+    /// Creates a new `BitReader` at the start of the stream.
+    pub fn new(inner: R) -> Self {
+        BitReader {
+            inner,
+            byte: 0,
+            left: 0,
+            pending: [0; 8],
+            unread: 0..0,
+            position: 0,
+        }
+    }
+
+    /// Number of bits read
+    #[inline(always)]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Check if the reader is at a byte boundary
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.left == 0
+    }
+
+    /// Read one bit
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitReader;
+    /// let mut reader = BitReader::new(&[0b1000_0000_u8][..]);
+    /// assert_eq!(reader.read_bit().unwrap(), true);
+    /// assert_eq!(reader.read_bit().unwrap(), false);
+    /// assert_eq!(reader.position(), 2);
+    /// ```
+    pub fn read_bit(&mut self) -> io::Result<bool> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    /// Read n bits as an unsigned value
+    /// # Parameters
+    /// - `n`: the number of bits to read, 0 <= n <= 64
+    /// # Errors
+    /// - `UnexpectedEof` if the stream ends partway through the value, the bits
+    ///   already taken from the stream are kept and `position` is unchanged,
+    ///   so the read can be retried once the stream has more data
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitReader;
+    /// let mut reader = BitReader::new(&[0b1010_0000, 0b0110_1001][..]);
+    /// assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+    /// assert_eq!(reader.read_bits(13).unwrap(), 0b0_0000_0110_1001);
+    /// assert!(reader.read_bits(1).is_err());
+    /// ```
+    pub fn read_bits(&mut self, n: usize) -> io::Result<u64> {
+        assert_overflow!(n, 0, u64::BITS, "read_bits");
+        let (byte, left) = (self.byte, self.left);
+        let (mut taken, mut count) = ([0; 8], 0);
+        let mut value = 0_u64;
+        let mut need = n;
+        while need > 0 {
+            if self.left == 0 {
+                match self.next_byte(n - need, n) {
+                    Ok(next) => {
+                        (taken[count], count) = (next, count + 1);
+                        (self.byte, self.left) = (next, 8);
+                    }
+                    Err(e) => {
+                        // rewind to the start of the value, keeping the bytes taken
+                        (self.byte, self.left) = (byte, left);
+                        (self.pending, self.unread) = (taken, 0..count);
+                        return Err(e);
+                    }
+                }
+            }
+            let take = need.min(self.left);
+            let bits = (self.byte >> (self.left - take)) & (0xff >> (8 - take));
+            value = (value << take) | bits as u64;
+            self.left -= take;
+            need -= take;
+        }
+        self.position += n as u64;
+        Ok(value)
+    }
+
+    /// Read n bits as a two's complement signed value
+    /// # Parameters
+    /// - `n`: the number of bits to read, 1 <= n <= 64
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitReader;
+    /// let mut reader = BitReader::new(&[0b1110_0111][..]);
+    /// assert_eq!(reader.read_signed(4).unwrap(), -2);
+    /// assert_eq!(reader.read_signed(4).unwrap(), 7);
+    /// ```
+    pub fn read_signed(&mut self, n: usize) -> io::Result<i64> {
+        assert_overflow!(n, 1, u64::BITS, "read_signed");
+        let shift = u64::BITS as usize - n;
+        Ok(((self.read_bits(n)? << shift) as i64) >> shift)
+    }
+
+    /// Read bytes to fill `buf`, reading directly from the stream at a byte boundary
+    /// # Errors
+    /// - `UnexpectedEof` if the stream ends partway through `buf`, the bytes
+    ///   read are at the start of `buf` and counted in `position`
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitReader;
+    /// let mut reader = BitReader::new(&[0x12, 0x34, 0x56][..]);
+    /// let mut buf = [0; 2];
+    /// assert_eq!(reader.read_bits(4).unwrap(), 0x1);
+    /// reader.read_bytes(&mut buf).unwrap();
+    /// assert_eq!(buf, [0x23, 0x45]);
+    /// ```
+    pub fn read_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        if self.is_aligned() && self.unread.is_empty() {
+            let mut filled = 0;
+            while filled < buf.len() {
+                match self.inner.read(&mut buf[filled..]) {
+                    Ok(0) => {
+                        let e = io::ErrorKind::UnexpectedEof.into();
+                        return Err(self.eof_error(e, 0, (buf.len() - filled) * 8));
+                    }
+                    Ok(len) => {
+                        filled += len;
+                        self.position += len as u64 * 8;
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        } else {
+            for byte in buf.iter_mut() {
+                *byte = self.read_bits(8)? as u8;
+            }
+        }
+        Ok(())
+    }
+
+    /// Skip the remaining bits of the current byte, returns the number of skipped bits
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitReader;
+    /// let mut reader = BitReader::new(&[0x12, 0x34][..]);
+    /// assert_eq!(reader.read_bits(3).unwrap(), 0);
+    /// assert_eq!(reader.align_to_byte(), 5);
+    /// assert_eq!(reader.read_bits(8).unwrap(), 0x34);
+    /// ```
+    pub fn align_to_byte(&mut self) -> usize {
+        let skipped = std::mem::take(&mut self.left);
+        self.position += skipped as u64;
+        skipped
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the underlying reader, unread bits of the current byte and
+    /// bytes kept by a failed read are lost.
+    #[inline(always)]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read the next byte of a value of n bits, `read` bits of which are already read
+    fn next_byte(&mut self, read: usize, n: usize) -> io::Result<u8> {
+        if let Some(i) = self.unread.next() {
+            return Ok(self.pending[i]);
+        }
+        let mut byte = [0];
+        match self.inner.read_exact(&mut byte) {
+            Ok(()) => Ok(byte[0]),
+            Err(e) => Err(self.eof_error(e, read, n)),
+        }
+    }
+
+    /// Describe the end of stream partway through a value
+    fn eof_error(&self, e: io::Error, read: usize, n: usize) -> io::Error {
+        match e.kind() {
+            io::ErrorKind::UnexpectedEof => io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "[nbits] end of stream at bit `{}` reading `{read}` of `{n}` bits",
+                    self.position + read as u64
+                ),
+            ),
+            _ => e,
+        }
+    }
+}
+
+/**
+ * `BitWriter` writes bits to any `Write` stream, the highest bit of each byte first.
+ *
+ * Completed bytes are written to the stream immediately, wrap it in a `BufWriter` for unbuffered sinks.
+ * Bits of a partial byte are kept until the byte is completed, see [`BitWriter::pad_to_byte`].
+ */
+#[derive(Debug)]
+pub struct BitWriter<W: Write> {
+    inner: W,
+    // pending bits of a partial byte
+    rem: TinyBits,
+    position: u64,
+}
+
+impl<W: Write> BitWriter<W> {
+    /// Creates a new `BitWriter` at the start of the stream.
+    pub fn new(inner: W) -> Self {
+        BitWriter {
+            inner,
+            rem: TinyBits::default(),
+            position: 0,
+        }
+    }
+
+    /// Number of bits written
+    #[inline(always)]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Check if the writer is at a byte boundary
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.rem.len() == 0
+    }
+
+    /// Write one bit
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitWriter;
+    /// let mut writer = BitWriter::new(vec![]);
+    /// writer.write_bit(true).unwrap();
+    /// writer.pad_to_byte(false).unwrap();
+    /// assert_eq!(writer.into_inner(), [0b1000_0000]);
+    /// ```
+    pub fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    /// Write the n bits of an unsigned value
+    /// # Parameters
+    /// - `value`: the value to write, must fit in n bits
+    /// - `n`: the number of bits to write, 0 <= n <= 64
+    /// # Errors
+    /// - `InvalidInput` if the value doesn't fit in n bits, nothing is written
+    /// - errors of the stream, the bytes accepted before the error are counted
+    ///   in `position` and the rest of the value is dropped, so the write can
+    ///   be resumed with the low bits of the value which are not counted
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitWriter;
+    /// let mut writer = BitWriter::new(vec![]);
+    /// writer.write_bits(0b101, 3).unwrap();
+    /// writer.write_bits(0b0_0000_0110_1001, 13).unwrap();
+    /// assert_eq!(writer.position(), 16);
+    /// assert_eq!(writer.into_inner(), [0b1010_0000, 0b0110_1001]);
+    /// ```
+    pub fn write_bits(&mut self, value: u64, n: usize) -> io::Result<()> {
+        assert_overflow!(n, 0, u64::BITS, "write_bits");
+        if n < u64::BITS as usize && value >> n != 0 {
+            let e = Error::ValueOverflow {
+                value: value as u128,
+                width: n,
+            };
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, e));
+        }
+
+        // 0~7 pending bits and at most 64 new bits
+        let (mut bytes, mut count) = ([0_u8; 9], 0);
+        let mut rem = self.rem;
+        rem.push(value as u128, n, &mut |byte| {
+            bytes[count] = byte;
+            count += 1;
+        });
+        let mut written = 0;
+        match self.write_stream(&bytes[..count], &mut written) {
+            Ok(()) => {
+                self.rem = rem;
+                self.position += n as u64;
+                Ok(())
+            }
+            Err(e) => {
+                // the pending bits are in the first byte, counted before
+                if written > 0 {
+                    self.position += (written * 8 - self.rem.len()) as u64;
+                    self.rem = TinyBits::default();
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Write the n bits of a two's complement signed value
+    /// # Parameters
+    /// - `value`: the value to write, must fit in n bits
+    /// - `n`: the number of bits to write, 1 <= n <= 64
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitWriter;
+    /// let mut writer = BitWriter::new(vec![]);
+    /// writer.write_signed(-2, 4).unwrap();
+    /// writer.write_signed(7, 4).unwrap();
+    /// assert!(writer.write_signed(8, 4).is_err());
+    /// assert_eq!(writer.into_inner(), [0b1110_0111]);
+    /// ```
+    pub fn write_signed(&mut self, value: i64, n: usize) -> io::Result<()> {
+        assert_overflow!(n, 1, u64::BITS, "write_signed");
+        let shift = u64::BITS as usize - n;
+        if (value << shift) >> shift != value {
+            let e = Error::SignedOverflow {
+                value: value as i128,
+                width: n,
+            };
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, e));
+        }
+        self.write_bits((value as u64) << shift >> shift, n)
+    }
+
+    /// Write bytes, directly to the stream at a byte boundary
+    /// # Errors
+    /// - errors of the stream, the bytes accepted before the error are counted in `position`
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitWriter;
+    /// let mut writer = BitWriter::new(vec![]);
+    /// writer.write_bits(0x1, 4).unwrap();
+    /// writer.write_bytes(&[0x23, 0x45]).unwrap();
+    /// writer.write_bits(0x6, 4).unwrap();
+    /// assert_eq!(writer.into_inner(), [0x12, 0x34, 0x56]);
+    /// ```
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        if self.is_aligned() {
+            let mut written = 0;
+            let result = self.write_stream(bytes, &mut written);
+            self.position += written as u64 * 8;
+            result?;
+        } else {
+            for &byte in bytes {
+                self.write_bits(byte as u64, 8)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Complete the partial byte with `fill_bit`, returns the number of padded bits
+    /// # Examples
+    /// ```
+    /// # use nbits::io::BitWriter;
+    /// let mut writer = BitWriter::new(vec![]);
+    /// writer.write_bits(0b101, 3).unwrap();
+    /// assert_eq!(writer.pad_to_byte(true).unwrap(), 5);
+    /// assert_eq!(writer.pad_to_byte(true).unwrap(), 0);
+    /// assert_eq!(writer.into_inner(), [0b1011_1111]);
+    /// ```
+    pub fn pad_to_byte(&mut self, fill_bit: bool) -> io::Result<usize> {
+        if self.is_aligned() {
+            return Ok(0);
+        }
+        let n = 8 - self.rem.len();
+        let fill = match fill_bit {
+            true => 0xff >> self.rem.len(),
+            false => 0,
+        };
+        self.write_bits(fill, n)?;
+        Ok(n)
+    }
+
+    /// Flush the underlying stream, bits of a partial byte are kept
+    #[inline(always)]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the underlying writer, bits of a partial byte are lost.
+    #[inline(always)]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Write all bytes to the stream, `written` counts the bytes accepted even if it fails
+    fn write_stream(&mut self, bytes: &[u8], written: &mut usize) -> io::Result<()> {
+        while *written < bytes.len() {
+            match self.inner.write(&bytes[*written..]) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(len) => *written += len,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> BitRead for BitReader<R> {
+    type Error = io::Error;
+
+    #[inline(always)]
+    fn read_bit(&mut self) -> io::Result<bool> {
+        BitReader::read_bit(self)
+    }
+
+    #[inline(always)]
+    fn read_bits(&mut self, n: usize) -> io::Result<u64> {
+        BitReader::read_bits(self, n)
+    }
+}
+
+impl<W: Write> BitWrite for BitWriter<W> {
+    type Error = io::Error;
+
+    #[inline(always)]
+    fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+        BitWriter::write_bit(self, bit)
+    }
+
+    #[inline(always)]
+    fn write_bits(&mut self, value: u64, n: usize) -> io::Result<()> {
+        BitWriter::write_bits(self, value, n)
+    }
+}
+
+/// Values which can't be written are `InvalidInput`, other errors are `InvalidData`
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        let kind = match e {
+            Error::ValueOverflow { .. }
+            | Error::SignedOverflow { .. }
+            | Error::Unrepresentable { .. } => io::ErrorKind::InvalidInput,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, e)
+    }
+}
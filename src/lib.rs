@@ -47,13 +47,52 @@
 //!     vec![0b001111_00, 0b1111_0011, 0b1100_0000]
 //! );
 //! ```
+//!
+//! # Features
+//! - `std` (default): `io` adapters over `std::io` readers and writers, implies `alloc`.
+//! - `alloc`: `BitVec`, `FromBits` for `Vec<u8>` and `Box<[u8]>`, and the slice
+//!   `bit_be_mul`, `bit_be_div` and `bit_be_rem`, which allocate their scratch.
+//!   Without it the crate only needs `core`: the `*_with_scratch` variants take the
+//!   scratch from the caller, and the `NBits` and `UBits` operators keep it on the stack.
+//! - `serde`: serialization of `NBits`, `UBits` and `BitVec`, implies `alloc`.
+//! - `rand`: random `NBits` from `rand::distributions::Standard`, uniform
+//!   `NBits::random_below` and `NBits::random_range`, and `BitsMut::fill_random`.
+//! - `num-traits`: `Num`, `Bounded`, checked, wrapping and saturating arithmetic,
+//!   primitive conversions and `Pow` for `NBits`, from the `num-traits` crate.
+
+#![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod bitvec;
+pub mod codes;
 pub mod core;
+mod error;
+#[cfg(feature = "std")]
+pub mod io;
 mod nbits;
+#[cfg(feature = "num-traits")]
+mod num;
+mod numeric;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod ubits;
+pub mod varint;
 mod xbits;
 
-pub use core::FromBits;
+#[cfg(feature = "alloc")]
+pub use bitvec::BitVec;
+pub use core::{FromBits, TryFromBits};
+pub use error::Error;
 pub use nbits::NBits;
+pub use numeric::Numeric;
+pub use ubits::UBits;
 pub use xbits::{BitsMut, BitsRef, XBits};
 
 /// Assert overflow of parameter
@@ -72,3 +111,33 @@ macro_rules! assert_overflow {
     };
 }
 pub(crate) use assert_overflow;
+
+/// `NBits` literal parsed at compile time from a hex (`0x`), binary (`0b`)
+/// or decimal literal, of any size. The width `N` is inferred, or given
+/// before the literal. A literal which overflows `N` bytes fails to compile.
+/// # Examples
+/// ```
+/// use nbits::{nbits, NBits};
+///
+/// // the order of secp256k1
+/// const ORDER: NBits<32> =
+///     nbits!(0xFFFFFFFF_FFFFFFFF_FFFFFFFF_FFFFFFFE_BAAEDCE6_AF48A03B_BFD25E8C_D0364141);
+/// assert_eq!(ORDER.0[31], 0x41);
+///
+/// assert_eq!(nbits!(2; 65535), NBits([0xff, 0xff]));
+/// assert_eq!(nbits!(3; "0x1_0000"), NBits([0x01, 0x00, 0x00]));
+/// ```
+///
+/// ```compile_fail
+/// # use nbits::{nbits, NBits};
+/// const TOO_BIG: NBits<2> = nbits!(65536);
+/// ```
+#[macro_export]
+macro_rules! nbits {
+    ($n: expr; $literal: literal) => {
+        const { $crate::NBits::<$n>::from_literal(stringify!($literal)) }
+    };
+    ($literal: literal) => {
+        const { $crate::NBits::from_literal(stringify!($literal)) }
+    };
+}
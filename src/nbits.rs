@@ -1,5 +1,5 @@
 use super::core::{BitArith, Bitwise};
-use crate::{BitsMut, BitsRef, XBits};
+use crate::{BitsMut, BitsRef, Error, Numeric, TryFromBits, XBits};
 
 /**
  *  `NBits` is a wrapper around an array of bytes that provides
@@ -13,9 +13,97 @@ pub struct NBits<const N: usize>(pub [u8; N]);
 impl<const N: usize> NBits<N> {
     /// Creates a new `NBits` instance with all bits set to 0.
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self([0; N])
     }
+
+    /// The value 1, panics if `N` is zero
+    #[inline(always)]
+    pub(crate) const fn one() -> Self {
+        assert!(N > 0, "[nbits] `NBits<0>` can't hold 1");
+        let mut value = Self::new();
+        value.0[N - 1] = 1;
+        value
+    }
+
+    /// Parses a hex (`0x`), binary (`0b`) or decimal literal, with `_` separators
+    /// after the first digit and optional quotes, as `nbits!` does at compile time.
+    /// # Panics
+    /// Panics if a digit is invalid or the value overflows `N` bytes.
+    /// # Examples
+    /// ```
+    /// # use nbits::NBits;
+    /// assert_eq!(NBits::<2>::from_literal("0x1_ff"), NBits([0x01, 0xff]));
+    /// assert_eq!(NBits::<2>::from_literal("511"), NBits([0x01, 0xff]));
+    /// assert_eq!(NBits::<2>::from_literal("0b1_1111_1111"), NBits([0x01, 0xff]));
+    /// ```
+    pub const fn from_literal(literal: &str) -> Self {
+        let digits = match literal.as_bytes() {
+            [b'"', inner @ .., b'"'] => inner,
+            digits => digits,
+        };
+        match Self::parse_literal(digits) {
+            Ok(value) => value,
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+
+    /// Parses a hex (`0x`), binary (`0b`) or decimal literal with optional `_` separators
+    pub(crate) const fn parse_literal(mut digits: &[u8]) -> Result<Self, &'static str> {
+        let radix = match digits {
+            [b'0', b'x' | b'X', rest @ ..] => {
+                digits = rest;
+                16
+            }
+            [b'0', b'b' | b'B', rest @ ..] => {
+                digits = rest;
+                2
+            }
+            _ => 10,
+        };
+
+        Self::parse_radix(digits, radix)
+    }
+
+    /// Parses digits of `radix` in `2..=36` with optional `_` separators after the first digit
+    pub(crate) const fn parse_radix(digits: &[u8], radix: u8) -> Result<Self, &'static str> {
+        let mut value = Self::new();
+        let mut count = 0;
+        let mut i = 0;
+        while i < digits.len() {
+            let digit = match digits[i] {
+                b'_' if count > 0 => {
+                    i += 1;
+                    continue;
+                }
+                c @ b'0'..=b'9' => c - b'0',
+                c @ b'a'..=b'z' => c - b'a' + 10,
+                c @ b'A'..=b'Z' => c - b'A' + 10,
+                _ => radix,
+            };
+            if digit >= radix {
+                return Err("[nbits] invalid digit in literal");
+            }
+
+            // value = value * radix + digit
+            let mut carry = digit as u16;
+            let mut j = N;
+            while j > 0 {
+                j -= 1;
+                let v = value.0[j] as u16 * radix as u16 + carry;
+                (value.0[j], carry) = (v as u8, v >> 8);
+            }
+            if carry != 0 {
+                return Err("[nbits] literal overflow `NBits<N>`");
+            }
+            count += 1;
+            i += 1;
+        }
+        match count {
+            0 => Err("[nbits] literal without digits"),
+            _ => Ok(value),
+        }
+    }
 }
 
 impl<const N: usize> Default for NBits<N> {
@@ -25,317 +113,681 @@ impl<const N: usize> Default for NBits<N> {
     }
 }
 
-impl<const N: usize> std::fmt::Display for NBits<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        for byte in self.0.iter() {
-            s.push_str(&format!("{:08b} ", byte));
-        }
-        write!(f, "{}", s)
+impl<const N: usize> AsRef<[u8]> for NBits<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
     }
 }
 
-impl<const N: usize> std::ops::Add<&NBits<N>> for NBits<N> {
-    type Output = Self;
-
-    #[inline(always)]
-    fn add(mut self, other: &Self) -> Self::Output {
-        let overflow = self.0.bit_be_add(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `add`");
-        self
+impl<const N: usize> core::fmt::Display for NBits<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|byte| write!(f, "{:08b} ", byte))
     }
 }
 
-impl<const N: usize> std::ops::Sub<&NBits<N>> for NBits<N> {
-    type Output = Self;
+/// Arithmetic operators panic on overflow, every combination of
+/// owned and borrowed operands delegates to `NBits<N> op &NBits<N>`.
+/// Primitive operands wider than `N` bytes are computed in 8 bytes with `$wide`.
+macro_rules! nbits_arith {
+    ($($op: ident, $fn: ident, $op_assign: ident, $fn_assign: ident, $arith: expr, $wide: expr;)*) => {$(
+        impl<const N: usize> core::ops::$op<&NBits<N>> for NBits<N> {
+            type Output = Self;
 
-    #[inline(always)]
-    fn sub(mut self, other: &Self) -> Self::Output {
-        let overflow = self.0.bit_be_sub(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `sub`");
-        self
-    }
-}
+            #[inline(always)]
+            fn $fn(mut self, other: &Self) -> Self::Output {
+                let overflow = $arith(&mut self.0, &other.0);
+                assert!(!overflow, concat!("[nbits] Overflow in `", stringify!($fn), "`"));
+                self
+            }
+        }
 
-impl<const N: usize> std::ops::Mul<&NBits<N>> for NBits<N> {
-    type Output = Self;
+        impl<const N: usize> core::ops::$op<NBits<N>> for NBits<N> {
+            type Output = Self;
 
-    #[inline(always)]
-    fn mul(mut self, other: &Self) -> Self::Output {
-        let overflow = self.0.bit_be_mul(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `mul`");
-        self
-    }
-}
+            #[inline(always)]
+            fn $fn(self, other: Self) -> Self::Output {
+                core::ops::$op::$fn(self, &other)
+            }
+        }
 
-impl<const N: usize> std::ops::Div<&NBits<N>> for NBits<N> {
-    type Output = Self;
+        impl<const N: usize> core::ops::$op<&NBits<N>> for &NBits<N> {
+            type Output = NBits<N>;
 
-    #[inline(always)]
-    fn div(mut self, other: &Self) -> Self::Output {
-        let overflow = self.0.bit_be_div(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `div`");
-        self
+            #[inline(always)]
+            fn $fn(self, other: &NBits<N>) -> Self::Output {
+                core::ops::$op::$fn(self.clone(), other)
+            }
+        }
+
+        impl<const N: usize> core::ops::$op<NBits<N>> for &NBits<N> {
+            type Output = NBits<N>;
+
+            #[inline(always)]
+            fn $fn(self, other: NBits<N>) -> Self::Output {
+                core::ops::$op::$fn(self.clone(), &other)
+            }
+        }
+
+        impl<const N: usize> core::ops::$op_assign<&NBits<N>> for NBits<N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: &Self) {
+                let overflow = $arith(&mut self.0, &other.0);
+                assert!(!overflow, concat!("[nbits] Overflow in `", stringify!($fn_assign), "`"));
+            }
+        }
+
+        impl<const N: usize> core::ops::$op_assign<NBits<N>> for NBits<N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: Self) {
+                core::ops::$op_assign::$fn_assign(self, &other)
+            }
+        }
+
+        impl<const N: usize, U: Into<u64>> core::ops::$op<U> for NBits<N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(mut self, other: U) -> Self::Output {
+                let overflow = arith_int(&mut self.0, other.into(), $arith, $wide);
+                assert!(!overflow, concat!("[nbits] Overflow in `", stringify!($fn), "`"));
+                self
+            }
+        }
+
+        impl<const N: usize, U: Into<u64>> core::ops::$op<U> for &NBits<N> {
+            type Output = NBits<N>;
+
+            #[inline(always)]
+            fn $fn(self, other: U) -> Self::Output {
+                core::ops::$op::$fn(self.clone(), other)
+            }
+        }
+
+        impl<const N: usize, U: Into<u64>> core::ops::$op_assign<U> for NBits<N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: U) {
+                let overflow = arith_int(&mut self.0, other.into(), $arith, $wide);
+                assert!(!overflow, concat!("[nbits] Overflow in `", stringify!($fn_assign), "`"));
+            }
+        }
+    )*};
+}
+
+nbits_arith! {
+    Add, add, AddAssign, add_assign, <[u8]>::bit_be_add, <[u8]>::bit_be_add;
+    Sub, sub, SubAssign, sub_assign, <[u8]>::bit_be_sub, <[u8]>::bit_be_sub;
+    Mul, mul, MulAssign, mul_assign, be_mul::<N>, be_mul::<8>;
+    Div, div, DivAssign, div_assign, be_div::<N>, be_div::<8>;
+    Rem, rem, RemAssign, rem_assign, be_rem::<N>, be_rem::<8>;
+}
+
+/// `data op other` with a primitive operand, returns true if overflow.
+/// An operand wider than `N` bytes, where `N < 8`, is not truncated: the
+/// operation is done in 8 bytes by `wide`, and overflows if the result
+/// doesn't fit back in `N` bytes.
+fn arith_int<const N: usize>(
+    data: &mut [u8; N],
+    other: u64,
+    arith: fn(&mut [u8], &[u8]) -> bool,
+    wide: fn(&mut [u8], &[u8]) -> bool,
+) -> bool {
+    let other = other.to_be_bytes();
+    let high = other.len().saturating_sub(N);
+    match other[..high].iter().all(|&v| v == 0) {
+        true => arith(data, &other[high..]),
+        false => {
+            let mut value = [0; 8];
+            value[high..].copy_from_slice(data);
+            let overflow = wide(&mut value, &other);
+            data.copy_from_slice(&value[high..]);
+            overflow || value[..high].iter().any(|&v| v != 0)
+        }
     }
 }
 
-impl<const N: usize> std::ops::Rem<&NBits<N>> for NBits<N> {
-    type Output = Self;
+/// `*=` with the scratch on the stack
+#[inline(always)]
+pub(crate) fn be_mul<const N: usize>(data: &mut [u8], other: &[u8]) -> bool {
+    data.bit_be_mul_with_scratch(other, &mut [0; N])
+}
 
-    #[inline(always)]
-    fn rem(mut self, other: &Self) -> Self::Output {
-        let overflow = self.0.bit_be_rem(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `rem`");
-        self
-    }
+/// `/=` with the scratch on the stack
+#[inline(always)]
+pub(crate) fn be_div<const N: usize>(data: &mut [u8], other: &[u8]) -> bool {
+    data.bit_be_div_with_scratch(other, &mut [0; N])
 }
 
-impl<const N: usize> std::ops::AddAssign<&NBits<N>> for NBits<N> {
-    #[inline(always)]
-    fn add_assign(&mut self, other: &Self) {
-        let overflow = self.0.bit_be_add(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `add_assign`");
-    }
+/// `%=` with the scratch on the stack
+#[inline(always)]
+pub(crate) fn be_rem<const N: usize>(data: &mut [u8], other: &[u8]) -> bool {
+    data.bit_be_rem_with_scratch(other, &mut [0; N])
 }
 
-impl<const N: usize> std::ops::SubAssign<&NBits<N>> for NBits<N> {
-    #[inline(always)]
-    fn sub_assign(&mut self, other: &Self) {
-        let overflow = self.0.bit_be_sub(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `sub_assign`");
-    }
+/// Bitwise operators for every combination of owned and borrowed operands
+macro_rules! nbits_bitwise {
+    ($($op: ident, $fn: ident, $op_assign: ident, $fn_assign: ident, $bitwise: ident;)*) => {$(
+        impl<const N: usize> core::ops::$op<&NBits<N>> for NBits<N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(mut self, other: &Self) -> Self::Output {
+                self.0.$bitwise(&other.0);
+                self
+            }
+        }
+
+        impl<const N: usize> core::ops::$op<NBits<N>> for NBits<N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, other: Self) -> Self::Output {
+                core::ops::$op::$fn(self, &other)
+            }
+        }
+
+        impl<const N: usize> core::ops::$op<&NBits<N>> for &NBits<N> {
+            type Output = NBits<N>;
+
+            #[inline(always)]
+            fn $fn(self, other: &NBits<N>) -> Self::Output {
+                core::ops::$op::$fn(self.clone(), other)
+            }
+        }
+
+        impl<const N: usize> core::ops::$op<NBits<N>> for &NBits<N> {
+            type Output = NBits<N>;
+
+            #[inline(always)]
+            fn $fn(self, other: NBits<N>) -> Self::Output {
+                core::ops::$op::$fn(self.clone(), &other)
+            }
+        }
+
+        impl<const N: usize> core::ops::$op_assign<&NBits<N>> for NBits<N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: &Self) {
+                self.0.$bitwise(&other.0);
+            }
+        }
+
+        impl<const N: usize> core::ops::$op_assign<NBits<N>> for NBits<N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: Self) {
+                self.0.$bitwise(&other.0);
+            }
+        }
+    )*};
 }
 
-impl<const N: usize> std::ops::MulAssign<&NBits<N>> for NBits<N> {
-    #[inline(always)]
-    fn mul_assign(&mut self, other: &Self) {
-        let overflow = self.0.bit_be_mul(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `mul_assign`");
-    }
+nbits_bitwise! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, bit_be_and;
+    BitOr, bitor, BitOrAssign, bitor_assign, bit_be_or;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, bit_be_xor;
 }
 
-impl<const N: usize> std::ops::DivAssign<&NBits<N>> for NBits<N> {
-    #[inline(always)]
-    fn div_assign(&mut self, other: &Self) {
-        let overflow = self.0.bit_be_div(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `div_assign`");
-    }
+/// Shift operators drop the bits shifted out
+macro_rules! nbits_shift {
+    ($($op: ident, $fn: ident, $op_assign: ident, $fn_assign: ident, $shift: ident;)*) => {$(
+        impl<const N: usize> core::ops::$op<usize> for NBits<N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(mut self, rhs: usize) -> Self::Output {
+                self.0.$shift(rhs);
+                self
+            }
+        }
+
+        impl<const N: usize> core::ops::$op<usize> for &NBits<N> {
+            type Output = NBits<N>;
+
+            #[inline(always)]
+            fn $fn(self, rhs: usize) -> Self::Output {
+                core::ops::$op::$fn(self.clone(), rhs)
+            }
+        }
+
+        impl<const N: usize> core::ops::$op_assign<usize> for NBits<N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, rhs: usize) {
+                self.0.$shift(rhs);
+            }
+        }
+    )*};
 }
 
-impl<const N: usize> std::ops::RemAssign<&NBits<N>> for NBits<N> {
-    #[inline(always)]
-    fn rem_assign(&mut self, other: &Self) {
-        let overflow = self.0.bit_be_rem(&other.0);
-        assert!(!overflow, "[nbits] Overflow in `rem_assign`");
-    }
+nbits_shift! {
+    Shl, shl, ShlAssign, shl_assign, bit_shl;
+    Shr, shr, ShrAssign, shr_assign, bit_shr;
 }
 
-impl<const N: usize> std::ops::BitAnd<&NBits<N>> for NBits<N> {
+impl<const N: usize> core::ops::Not for NBits<N> {
     type Output = Self;
 
     #[inline(always)]
-    fn bitand(mut self, other: &Self) -> Self::Output {
-        self.0.bit_be_and(&other.0);
+    fn not(mut self) -> Self::Output {
+        self.0.bit_not();
         self
     }
 }
 
-impl<const N: usize> std::ops::BitOr<&NBits<N>> for NBits<N> {
-    type Output = Self;
+impl<const N: usize> core::ops::Not for &NBits<N> {
+    type Output = NBits<N>;
 
     #[inline(always)]
-    fn bitor(mut self, other: &Self) -> Self::Output {
-        self.0.bit_be_or(&other.0);
-        self
+    fn not(self) -> Self::Output {
+        !self.clone()
     }
 }
 
-impl<const N: usize> std::ops::BitXor<&NBits<N>> for NBits<N> {
+/// Wrapping negation, the two's complement `2^(N * 8) - self`
+/// # Examples
+/// ```
+/// # use nbits::NBits;
+/// assert_eq!(-NBits([0, 1]), NBits([0xff, 0xff]));
+/// assert_eq!(-NBits([0, 0]), NBits([0, 0]));
+/// assert_eq!(-NBits([0x80]), NBits([0x80]));
+/// ```
+impl<const N: usize> core::ops::Neg for NBits<N> {
     type Output = Self;
 
     #[inline(always)]
-    fn bitxor(mut self, other: &Self) -> Self::Output {
-        self.0.bit_be_xor(&other.0);
+    fn neg(mut self) -> Self::Output {
+        self.0.bit_not().bit_be_add(&[1]);
         self
     }
 }
 
-impl<const N: usize> std::ops::Shl<usize> for NBits<N> {
-    type Output = Self;
+impl<const N: usize> core::ops::Neg for &NBits<N> {
+    type Output = NBits<N>;
 
     #[inline(always)]
-    fn shl(mut self, rhs: usize) -> Self::Output {
-        self.0.bit_shl(rhs);
-        self
+    fn neg(self) -> Self::Output {
+        -self.clone()
     }
 }
 
-impl<const N: usize> std::ops::Shr<usize> for NBits<N> {
-    type Output = Self;
-
+/// Numeric comparisons with `u64`, whatever `N` is
+/// # Examples
+/// ```
+/// # use nbits::NBits;
+/// assert!(NBits([0, 0, 1, 0]) == 256);
+/// assert!(NBits([1]) < 256);
+/// assert!(NBits([1; 9]) > u64::MAX);
+/// ```
+impl<const N: usize> PartialEq<u64> for NBits<N> {
     #[inline(always)]
-    fn shr(mut self, rhs: usize) -> Self::Output {
-        self.0.bit_shr(rhs);
-        self
+    fn eq(&self, other: &u64) -> bool {
+        self.0.bit_be_cmp(&other.to_be_bytes()).is_eq()
     }
 }
 
-impl<const N: usize> std::ops::BitAndAssign<&NBits<N>> for NBits<N> {
+impl<const N: usize> PartialOrd<u64> for NBits<N> {
     #[inline(always)]
-    fn bitand_assign(&mut self, other: &Self) {
-        self.0.bit_be_and(&other.0);
+    fn partial_cmp(&self, other: &u64) -> Option<core::cmp::Ordering> {
+        Some(self.0.bit_be_cmp(&other.to_be_bytes()))
     }
 }
 
-impl<const N: usize> std::ops::BitOrAssign<&NBits<N>> for NBits<N> {
+impl<const N: usize> PartialEq<NBits<N>> for u64 {
     #[inline(always)]
-    fn bitor_assign(&mut self, other: &Self) {
-        self.0.bit_be_or(&other.0);
+    fn eq(&self, other: &NBits<N>) -> bool {
+        other == self
     }
 }
 
-impl<const N: usize> std::ops::BitXorAssign<&NBits<N>> for NBits<N> {
+impl<const N: usize> PartialOrd<NBits<N>> for u64 {
     #[inline(always)]
-    fn bitxor_assign(&mut self, other: &Self) {
-        self.0.bit_be_xor(&other.0);
+    fn partial_cmp(&self, other: &NBits<N>) -> Option<core::cmp::Ordering> {
+        other.partial_cmp(self).map(core::cmp::Ordering::reverse)
     }
 }
 
-impl<const N: usize> std::ops::Not for NBits<N> {
-    type Output = Self;
-
-    #[inline(always)]
-    fn not(mut self) -> Self::Output {
-        self.0.bit_not();
-        self
+/// Panics on overflow as `+`
+impl<const N: usize> core::iter::Sum for NBits<N> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(), |sum, value| sum + &value)
     }
 }
 
-impl<const N: usize> std::ops::ShlAssign<usize> for NBits<N> {
-    #[inline(always)]
-    fn shl_assign(&mut self, rhs: usize) {
-        self.0.bit_shl(rhs);
+impl<'a, const N: usize> core::iter::Sum<&'a NBits<N>> for NBits<N> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(), |sum, value| sum + value)
     }
 }
 
-impl<const N: usize> std::ops::ShrAssign<usize> for NBits<N> {
-    #[inline(always)]
-    fn shr_assign(&mut self, rhs: usize) {
-        self.0.bit_shr(rhs);
+/// Panics on overflow as `*`, the product of nothing is 1,
+/// which panics if `N` is zero
+/// # Examples
+/// ```
+/// # use nbits::NBits;
+/// let values = [NBits([0, 2]), NBits([0, 3]), NBits([0, 7])];
+/// assert_eq!(values.iter().product::<NBits<2>>(), 42);
+/// assert_eq!(values.iter().sum::<NBits<2>>(), 12);
+/// assert_eq!(std::iter::empty::<NBits<2>>().product::<NBits<2>>(), 1);
+/// ```
+impl<const N: usize> core::iter::Product for NBits<N> {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, value| product * &value)
     }
 }
 
-impl<const N: usize, U: Into<u64>> std::ops::Add<U> for NBits<N> {
-    type Output = Self;
+impl<'a, const N: usize> core::iter::Product<&'a NBits<N>> for NBits<N> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, value| product * value)
+    }
+}
 
+impl<const N: usize> NBits<N> {
     #[inline(always)]
-    fn add(mut self, other: U) -> Self::Output {
-        let overflow = self.0.bit_be_add(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `add`");
-        self
+    pub const fn bit(&self, index: usize) -> bool {
+        assert!(index < N * 8, "[nbits] Index out of bounds");
+        let byte_index = index / 8;
+        let bit_index = index % 8;
+        ((self.0[byte_index] >> (7 - bit_index)) & 1) == 1
     }
 }
 
-impl<const N: usize, U: Into<u64>> std::ops::Sub<U> for NBits<N> {
-    type Output = Self;
+/// Bit operations named as the primitive integers' ones, counts are `u32`
+/// # Examples
+/// ```
+/// # use nbits::NBits;
+/// let value = NBits([0b0001_0000, 0b0000_0011]);
+/// assert_eq!(value.count_ones(), 3);
+/// assert_eq!(value.leading_zeros(), 3);
+/// assert_eq!(value.trailing_ones(), 2);
+/// assert_eq!(value.rotate_left(4), NBits([0b0000_0000, 0b0011_0001]));
+/// assert_eq!(value.swap_bytes(), NBits([0b0000_0011, 0b0001_0000]));
+/// assert_eq!(value.reverse_bits(), NBits([0b1100_0000, 0b0000_1000]));
+/// assert_eq!(NBits([0, 3]).pow(5), 243);
+/// ```
+impl<const N: usize> NBits<N> {
+    #[inline(always)]
+    pub fn count_ones(&self) -> u32 {
+        self.0.bit_count_ones() as u32
+    }
 
     #[inline(always)]
-    fn sub(mut self, other: U) -> Self::Output {
-        let overflow = self.0.bit_be_sub(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `sub`");
-        self
+    pub fn count_zeros(&self) -> u32 {
+        self.0.bit_count_zeros() as u32
     }
-}
 
-impl<const N: usize, U: Into<u64>> std::ops::Mul<U> for NBits<N> {
-    type Output = Self;
+    #[inline(always)]
+    pub fn leading_zeros(&self) -> u32 {
+        self.0.bit_leading_zeros() as u32
+    }
 
     #[inline(always)]
-    fn mul(mut self, other: U) -> Self::Output {
-        let overflow = self.0.bit_be_mul(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `mul`");
-        self
+    pub fn trailing_zeros(&self) -> u32 {
+        self.0.bit_trailing_zeros() as u32
     }
-}
 
-impl<const N: usize, U: Into<u64>> std::ops::Div<U> for NBits<N> {
-    type Output = Self;
+    #[inline(always)]
+    pub fn leading_ones(&self) -> u32 {
+        self.0.bit_leading_ones() as u32
+    }
 
     #[inline(always)]
-    fn div(mut self, other: U) -> Self::Output {
-        let overflow = self.0.bit_be_div(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `div`");
-        self
+    pub fn trailing_ones(&self) -> u32 {
+        self.0.bit_trailing_ones() as u32
     }
-}
 
-impl<const N: usize, U: Into<u64>> std::ops::Rem<U> for NBits<N> {
-    type Output = Self;
+    /// Shifts left by `n` bits, the high bits wrap around to the low end
+    pub fn rotate_left(&self, n: u32) -> Self {
+        let bits = N * 8;
+        if bits == 0 {
+            return self.clone();
+        }
+        let n = n as usize % bits;
+        let (mut high, mut low) = (self.clone(), self.clone());
+        high.0.bit_shl(n);
+        low.0.bit_shr(bits - n);
+        high.0.bit_be_or(&low.0);
+        high
+    }
 
+    /// Shifts right by `n` bits, the low bits wrap around to the high end
     #[inline(always)]
-    fn rem(mut self, other: U) -> Self::Output {
-        let overflow = self.0.bit_be_rem(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `rem`");
-        self
+    pub fn rotate_right(&self, n: u32) -> Self {
+        match N * 8 {
+            0 => self.clone(),
+            bits => self.rotate_left((bits - n as usize % bits) as u32),
+        }
     }
-}
 
-impl<const N: usize, U: Into<u64>> std::ops::AddAssign<U> for NBits<N> {
     #[inline(always)]
-    fn add_assign(&mut self, other: U) {
-        let overflow = self.0.bit_be_add(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `add_assign`");
+    pub const fn swap_bytes(&self) -> Self {
+        Self(reversed(self.0))
     }
-}
 
-impl<const N: usize, U: Into<u64>> std::ops::SubAssign<U> for NBits<N> {
     #[inline(always)]
-    fn sub_assign(&mut self, other: U) {
-        let overflow = self.0.bit_be_sub(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `sub_assign`");
+    pub fn reverse_bits(&self) -> Self {
+        let mut value = self.clone();
+        value.0.bit_reverse();
+        value
     }
-}
 
-impl<const N: usize, U: Into<u64>> std::ops::MulAssign<U> for NBits<N> {
-    #[inline(always)]
-    fn mul_assign(&mut self, other: U) {
-        let overflow = self.0.bit_be_mul(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `mul_assign`");
+    /// Raises to the power of `exp`, by squaring from the highest bit of `exp`
+    /// # Panics
+    /// Panics on overflow as `*`, or if `N` is zero.
+    pub fn pow(&self, exp: u32) -> Self {
+        let mut value = Self::one();
+        for i in (0..u32::BITS - exp.leading_zeros()).rev() {
+            value = &value * &value;
+            if exp >> i & 1 == 1 {
+                value *= self;
+            }
+        }
+        value
     }
 }
 
-impl<const N: usize, U: Into<u64>> std::ops::DivAssign<U> for NBits<N> {
-    #[inline(always)]
-    fn div_assign(&mut self, other: U) {
-        let overflow = self.0.bit_be_div(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `div_assign`");
+impl<const N: usize> NBits<N> {
+    /// Creates from big-endian bytes.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Creates from little-endian bytes.
+    /// # Examples
+    /// ```
+    /// # use nbits::NBits;
+    /// let value = NBits::from_le_bytes([0x34, 0x12, 0x00]);
+    /// assert_eq!(value, NBits::from(0x1234_u16));
+    /// assert_eq!(value.to_le_bytes(), [0x34, 0x12, 0x00]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; N]) -> Self {
+        Self(reversed(bytes))
+    }
+
+    /// The big-endian bytes
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; N] {
+        self.0
+    }
+
+    /// The little-endian bytes
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; N] {
+        reversed(self.0)
+    }
+
+    /// Converts to `M` bytes, zero-extended if wider,
+    /// fails with `Error::Truncated` if any dropped high byte is not zero.
+    /// # Examples
+    /// ```
+    /// # use nbits::{Error, NBits};
+    /// assert_eq!(NBits([0x01, 0x02]).resize::<3>(), Ok(NBits([0x00, 0x01, 0x02])));
+    /// assert_eq!(NBits([0x00, 0x01, 0x02]).resize::<2>(), Ok(NBits([0x01, 0x02])));
+    /// assert_eq!(
+    ///     NBits([0x01, 0x02]).resize::<1>(),
+    ///     Err(Error::Truncated { len: 2, max: 1 })
+    /// );
+    /// ```
+    pub const fn resize<const M: usize>(&self) -> Result<NBits<M>, Error> {
+        let mut value = NBits::<M>::new();
+        let mut i = 0;
+        while i < N {
+            match i + M < N {
+                true if self.0[i] != 0 => return Err(Error::Truncated { len: N, max: M }),
+                true => {}
+                false => value.0[i + M - N] = self.0[i],
+            }
+            i += 1;
+        }
+        Ok(value)
     }
 }
 
-impl<const N: usize, U: Into<u64>> std::ops::RemAssign<U> for NBits<N> {
+impl<const N: usize> NBits<N> {
+    /// Numeric comparison with `NBits` of another width
+    /// # Examples
+    /// ```
+    /// # use nbits::NBits;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!(NBits([0, 0, 1]).numeric_cmp(&NBits([1])), Ordering::Equal);
+    /// assert_eq!(NBits([1, 0]).numeric_cmp(&NBits([0, 0, 0xff])), Ordering::Greater);
+    /// ```
+    #[inline(always)]
+    pub fn numeric_cmp<const M: usize>(&self, other: &NBits<M>) -> core::cmp::Ordering {
+        self.0.bit_be_cmp(&other.0)
+    }
+
+    /// Numeric comparison with a primitive unsigned integer
+    /// # Examples
+    /// ```
+    /// # use nbits::NBits;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!(NBits([1, 0]).numeric_cmp_int(256_u16), Ordering::Equal);
+    /// assert_eq!(NBits([1; 17]).numeric_cmp_int(u128::MAX), Ordering::Greater);
+    /// ```
+    #[inline(always)]
+    pub fn numeric_cmp_int<U: Into<u128>>(&self, other: U) -> core::cmp::Ordering {
+        self.0.bit_be_cmp(&other.into().to_be_bytes())
+    }
+
+    /// Numeric view of the value
+    #[inline(always)]
+    pub const fn numeric(&self) -> Numeric<'_> {
+        Numeric::Be(&self.0)
+    }
+}
+
+/// Bytes in reverse order
+#[inline(always)]
+const fn reversed<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    let mut i = 0;
+    while i < N / 2 {
+        (bytes[i], bytes[N - 1 - i]) = (bytes[N - 1 - i], bytes[i]);
+        i += 1;
+    }
+    bytes
+}
+
+/// Lossless conversions from primitive unsigned integers into `NBits<N>`
+/// as wide as the integer up to 64 bytes, and fallible conversions back
+/// which fail with `Error::Truncated`.
+///
+/// `usize` has no lossless conversion as its size depends on the platform,
+/// other widths convert through the bytes with [`NBits::resize`].
+/// # Examples
+/// ```
+/// # use nbits::{Error, NBits};
+/// let value = NBits::<3>::from(0x1234_u16);
+/// assert_eq!(value, NBits([0x00, 0x12, 0x34]));
+/// assert_eq!(u16::try_from(value.clone()), Ok(0x1234));
+/// assert_eq!(u8::try_from(value), Err(Error::Truncated { len: 3, max: 1 }));
+/// assert_eq!(NBits(0x1234_usize.to_be_bytes()).resize::<2>(), Ok(NBits([0x12, 0x34])));
+/// ```
+/// `NBits<4>` is too narrow for `u64`:
+/// ```compile_fail
+/// # use nbits::NBits;
+/// let value = NBits::<4>::from(0x1234_u64);
+/// ```
+macro_rules! nbits_primitive {
+    ($($t: ty),*) => {$(
+        impl<const N: usize> TryFrom<NBits<N>> for $t {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(value: NBits<N>) -> Result<Self, Error> {
+                value.resize().map(|value| <$t>::from_be_bytes(value.0))
+            }
+        }
+    )*};
+}
+
+nbits_primitive!(u8, u16, u32, u64, u128, usize);
+
+/// Implements `From` for each of the primitive types `$t` at each width `$n`.
+macro_rules! nbits_from_primitive {
+    ($ts: tt: $($n: literal),*) => {$(
+        nbits_from_primitive!(@width $n, $ts);
+    )*};
+    (@width $n: literal, [$($t: ty),*]) => {$(
+        impl From<$t> for NBits<$n> {
+            #[inline(always)]
+            fn from(value: $t) -> Self {
+                NBits(value.to_be_bytes())
+                    .resize()
+                    .expect("zero-extend never truncates")
+            }
+        }
+    )*};
+}
+
+nbits_from_primitive!([u8]: 1);
+nbits_from_primitive!([u8, u16]: 2, 3);
+nbits_from_primitive!([u8, u16, u32]: 4, 5, 6, 7);
+nbits_from_primitive!([u8, u16, u32, u64]: 8, 9, 10, 11, 12, 13, 14, 15);
+nbits_from_primitive!([u8, u16, u32, u64, u128]:
+    16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+    32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+    48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+    64
+);
+
+impl<const N: usize> TryFromBits for NBits<N> {
+    /// # Examples
+    /// ```
+    /// # use nbits::{NBits, TryFromBits};
+    /// let bits = [true, true, true, true, false, false, false, false, true];
+    /// let value = NBits::<2>::try_from_bits(bits.into_iter());
+    /// assert_eq!(value, Ok(NBits([0b1111_0000, 0b1000_0000])));
+    /// ```
     #[inline(always)]
-    fn rem_assign(&mut self, other: U) {
-        let overflow = self.0.bit_be_rem(&other.into().to_be_bytes());
-        assert!(!overflow, "[nbits] Overflow in `rem_assign`");
+    fn try_from_bits<U>(bits: U) -> Result<Self, Error>
+    where
+        U: Iterator<Item = bool>,
+    {
+        <[u8; N]>::try_from_bits(bits).map(Self)
     }
-}
 
-impl<const N: usize> NBits<N> {
     #[inline(always)]
-    pub fn bit(&self, index: usize) -> bool {
-        assert!(index < N * 8, "[nbits] Index out of bounds");
-        let byte_index = index / 8;
-        let bit_index = index % 8;
-        ((self.0[byte_index] >> (7 - bit_index)) & 1) == 1
+    fn try_from_bits_chunk<T, U>(chunks: U, n: usize) -> Result<Self, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        <[u8; N]>::try_from_bits_chunk(chunks, n).map(Self)
     }
 }
 
 impl<const N: usize> XBits for NBits<N> {
-    fn bits(&self) -> BitsRef {
+    fn bits(&self) -> BitsRef<'_> {
         BitsRef(&self.0)
     }
 
-    fn bits_mut(&mut self) -> BitsMut {
+    fn bits_mut(&mut self) -> BitsMut<'_> {
         BitsMut(&mut self.0)
     }
 }
@@ -348,13 +800,13 @@ mod tests {
     fn test_nbits() {
         let mut nbits = NBits::<4>::new();
         nbits.0[0] = 0b10101010;
-        assert_eq!(nbits.bit(0), true);
-        assert_eq!(nbits.bit(1), false);
-        assert_eq!(nbits.bit(2), true);
-        assert_eq!(nbits.bit(3), false);
-        assert_eq!(nbits.bit(4), true);
-        assert_eq!(nbits.bit(5), false);
-        assert_eq!(nbits.bit(6), true);
-        assert_eq!(nbits.bit(7), false);
+        assert!(nbits.bit(0));
+        assert!(!nbits.bit(1));
+        assert!(nbits.bit(2));
+        assert!(!nbits.bit(3));
+        assert!(nbits.bit(4));
+        assert!(!nbits.bit(5));
+        assert!(nbits.bit(6));
+        assert!(!nbits.bit(7));
     }
 }
@@ -0,0 +1,211 @@
+//! `num-traits` support, enabled by the `num-traits` feature
+//!
+//! `NBits<N>` is an unsigned integer of `N` bytes. It isn't `Copy`, so it
+//! can't be `PrimInt`, but has the bit operations of it as inherent methods.
+//!
+//! # Examples
+//! ```
+//! use nbits::NBits;
+//! use num_traits::{CheckedMul, Num, One, Zero};
+//!
+//! fn horner<T: Num + Clone>(coefficients: &[T], x: &T) -> T {
+//!     coefficients
+//!         .iter()
+//!         .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+//! }
+//!
+//! let coefficients = [NBits([0, 2]), NBits([0, 0]), NBits([0, 1])];
+//! assert_eq!(horner(&coefficients, &NBits([0, 10])), 201);
+//!
+//! assert_eq!(NBits::<2>::from_str_radix("zz", 36), Ok(NBits([0x05, 0x0f])));
+//! assert!(NBits([1, 0]).checked_mul(&NBits([1, 0])).is_none());
+//! assert!(NBits::<2>::zero().is_zero() && NBits::<2>::one().is_one());
+//! ```
+
+use super::core::BitArith;
+use crate::nbits::{be_div, be_mul, be_rem};
+use crate::NBits;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, Num, One,
+    Pow, Saturating, ToPrimitive, WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+impl<const N: usize> Zero for NBits<N> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&v| v == 0)
+    }
+}
+
+/// Panics if `N` is zero, which has no one
+impl<const N: usize> One for NBits<N> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::one()
+    }
+}
+
+/// Parses digits of `radix` without prefix or separators
+/// # Panics
+/// Panics if `radix` is not in `2..=36`.
+impl<const N: usize> Num for NBits<N> {
+    type FromStrRadixErr = &'static str;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        assert!(
+            (2..=36).contains(&radix),
+            "[nbits] radix `{radix}` not in `2..=36`"
+        );
+        match str.contains('_') {
+            true => Err("[nbits] invalid digit in literal"),
+            false => Self::parse_radix(str.as_bytes(), radix as u8),
+        }
+    }
+}
+
+impl<const N: usize> Bounded for NBits<N> {
+    #[inline(always)]
+    fn min_value() -> Self {
+        Self::new()
+    }
+
+    #[inline(always)]
+    fn max_value() -> Self {
+        Self([0xff; N])
+    }
+}
+
+/// Implements checked and wrapping arithmetic with a slice operation
+/// returning the overflow flag.
+macro_rules! nbits_num {
+    ($($checked: ident, $checked_fn: ident, $wrapping: ident, $wrapping_fn: ident, $arith: expr;)*) => {$(
+        impl<const N: usize> $checked for NBits<N> {
+            #[inline]
+            fn $checked_fn(&self, v: &Self) -> Option<Self> {
+                let mut value = self.clone();
+                match $arith(&mut value.0, &v.0) {
+                    true => None,
+                    false => Some(value),
+                }
+            }
+        }
+
+        impl<const N: usize> $wrapping for NBits<N> {
+            #[inline]
+            fn $wrapping_fn(&self, v: &Self) -> Self {
+                let mut value = self.clone();
+                $arith(&mut value.0, &v.0);
+                value
+            }
+        }
+    )*};
+}
+
+nbits_num! {
+    CheckedAdd, checked_add, WrappingAdd, wrapping_add, <[u8]>::bit_be_add;
+    CheckedSub, checked_sub, WrappingSub, wrapping_sub, <[u8]>::bit_be_sub;
+    CheckedMul, checked_mul, WrappingMul, wrapping_mul, be_mul::<N>;
+}
+
+/// `None` on division by zero
+impl<const N: usize> CheckedDiv for NBits<N> {
+    #[inline]
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        let mut value = self.clone();
+        (!be_div::<N>(&mut value.0, &v.0)).then_some(value)
+    }
+}
+
+/// `None` on division by zero
+impl<const N: usize> CheckedRem for NBits<N> {
+    #[inline]
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        let mut value = self.clone();
+        (!be_rem::<N>(&mut value.0, &v.0)).then_some(value)
+    }
+}
+
+impl<const N: usize> Saturating for NBits<N> {
+    #[inline]
+    fn saturating_add(self, v: Self) -> Self {
+        self.checked_add(&v).unwrap_or_else(Self::max_value)
+    }
+
+    #[inline]
+    fn saturating_sub(self, v: Self) -> Self {
+        self.checked_sub(&v).unwrap_or_else(Self::min_value)
+    }
+}
+
+impl<const N: usize> ToPrimitive for NBits<N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64()?.try_into().ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.resize()
+            .ok()
+            .map(|v: NBits<8>| u64::from_be_bytes(v.0))
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128()?.try_into().ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.resize()
+            .ok()
+            .map(|v: NBits<16>| u128::from_be_bytes(v.0))
+    }
+}
+
+/// `None` for negative values or values wider than `N` bytes
+impl<const N: usize> FromPrimitive for NBits<N> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_u64(n.try_into().ok()?)
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        NBits(n.to_be_bytes()).resize().ok()
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::from_u128(n.try_into().ok()?)
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        NBits(n.to_be_bytes()).resize().ok()
+    }
+}
+
+/// Panics on overflow as `NBits::pow`
+impl<const N: usize> Pow<u32> for NBits<N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn pow(self, rhs: u32) -> Self::Output {
+        NBits::pow(&self, rhs)
+    }
+}
+
+impl<const N: usize> Pow<u32> for &NBits<N> {
+    type Output = NBits<N>;
+
+    #[inline(always)]
+    fn pow(self, rhs: u32) -> Self::Output {
+        NBits::pow(self, rhs)
+    }
+}
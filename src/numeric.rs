@@ -0,0 +1,82 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/**
+ *  `Numeric` views bytes as an unsigned integer of either byte
+ *  order, ordered by its numeric value whatever the length, so
+ *  byte slices can be sorted or used as keys by value. Leading
+ *  zeros are insignificant, `Eq` and `Hash` agree with `Ord`.
+ *
+ * # Examples
+ * ```
+ * use nbits::Numeric;
+ * use std::collections::BTreeMap;
+ *
+ * let mut map = BTreeMap::new();
+ * map.insert(Numeric::Be(&[0x01, 0x00]), "256");
+ * map.insert(Numeric::Be(&[0xff]), "255");
+ * map.insert(Numeric::Le(&[0x02]), "2");
+ * assert_eq!(map.values().copied().collect::<Vec<_>>(), ["2", "255", "256"]);
+ * assert_eq!(map.get(&Numeric::Le(&[0x00, 0x01, 0x00])), Some(&"256"));
+ * ```
+ */
+#[derive(Debug, Clone, Copy)]
+pub enum Numeric<'a> {
+    /// Big-endian bytes, the first byte is the most significant
+    Be(&'a [u8]),
+    /// Little-endian bytes, the last byte is the most significant
+    Le(&'a [u8]),
+}
+
+impl Numeric<'_> {
+    /// Number of bytes without the leading zeros
+    #[inline]
+    pub fn significant_len(&self) -> usize {
+        match *self {
+            Numeric::Be(bytes) => bytes.len() - bytes.iter().take_while(|&&v| v == 0).count(),
+            Numeric::Le(bytes) => bytes.len() - bytes.iter().rev().take_while(|&&v| v == 0).count(),
+        }
+    }
+
+    /// Significant bytes from the most significant one
+    #[inline]
+    fn significant(&self) -> impl Iterator<Item = u8> + '_ {
+        let len = self.significant_len();
+        let (be, le) = match *self {
+            Numeric::Be(bytes) => (&bytes[bytes.len() - len..], &[][..]),
+            Numeric::Le(bytes) => (&[][..], &bytes[..len]),
+        };
+        be.iter().chain(le.iter().rev()).copied()
+    }
+}
+
+impl PartialEq for Numeric<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Numeric<'_> {}
+
+impl PartialOrd for Numeric<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Numeric<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.significant_len()
+            .cmp(&other.significant_len())
+            .then_with(|| self.significant().cmp(other.significant()))
+    }
+}
+
+impl Hash for Numeric<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.significant_len());
+        self.significant().for_each(|v| state.write_u8(v));
+    }
+}
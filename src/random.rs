@@ -0,0 +1,121 @@
+//! Random generation, enabled by the `rand` feature
+//!
+//! Bounded values are drawn by rejection sampling: bits above the bound's
+//! bit length are cleared and draws not below the bound are retried, so
+//! every value is equally likely and each draw is accepted with a chance
+//! of more than one half.
+
+use super::core::{BitArith, Bitwise};
+use crate::{BitsMut, NBits};
+use core::ops::{Bound, RangeBounds};
+use rand::distributions::{Distribution, Standard};
+use rand::{Rng, RngCore};
+
+/// Every bit is uniformly random.
+impl<const N: usize> Distribution<NBits<N>> for Standard {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NBits<N> {
+        let mut value = NBits::new();
+        rng.fill_bytes(&mut value.0);
+        value
+    }
+}
+
+impl<const N: usize> NBits<N> {
+    /// Uniformly random value in `0..bound`
+    /// # Panics
+    /// Panics if `bound` is zero.
+    /// # Examples
+    /// ```
+    /// # use nbits::{nbits, NBits};
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// const ORDER: NBits<32> =
+    ///     nbits!(32; 0xFFFFFFFF_FFFFFFFF_FFFFFFFF_FFFFFFFE_BAAEDCE6_AF48A03B_BFD25E8C_D0364141);
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let scalar = NBits::random_below(&ORDER, &mut rng);
+    /// assert!(scalar < ORDER);
+    /// ```
+    pub fn random_below<R: Rng + ?Sized>(bound: &Self, rng: &mut R) -> Self {
+        let zeros = bound.0.bit_leading_zeros();
+        assert!(zeros < N * 8, "[nbits] random_below with zero bound");
+
+        let (full, part) = (zeros / 8, zeros % 8);
+        loop {
+            let mut value: Self = rng.gen();
+            value.0[..full].fill(0);
+            value.0[full] &= 0xff >> part;
+            if value < *bound {
+                return value;
+            }
+        }
+    }
+
+    /// Uniformly random value in `range`, which may be unbounded on either side
+    /// # Panics
+    /// Panics if `range` is empty.
+    /// # Examples
+    /// ```
+    /// # use nbits::NBits;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let value = NBits::random_range(NBits([0, 10])..=NBits([0, 20]), &mut rng);
+    /// assert!(10 <= value && value <= 20);
+    /// let value = NBits::random_range(NBits([1, 0]).., &mut rng);
+    /// assert!(value >= 256);
+    /// ```
+    pub fn random_range<B, R>(range: B, rng: &mut R) -> Self
+    where
+        B: RangeBounds<Self>,
+        R: Rng + ?Sized,
+    {
+        const EMPTY: &str = "[nbits] random_range with empty range";
+
+        let low = match range.start_bound() {
+            Bound::Included(start) => start.clone(),
+            Bound::Excluded(start) => {
+                let mut low = start.clone();
+                assert!(!low.0.bit_be_add(&[1]), "{EMPTY}");
+                low
+            }
+            Bound::Unbounded => Self::new(),
+        };
+        let high = match range.end_bound() {
+            Bound::Included(end) => end.clone(),
+            Bound::Excluded(end) => {
+                let mut high = end.clone();
+                assert!(!high.0.bit_be_sub(&[1]), "{EMPTY}");
+                high
+            }
+            Bound::Unbounded => Self([0xff; N]),
+        };
+        assert!(low <= high, "{EMPTY}");
+
+        // span = high - low + 1, which only overflows for the full range
+        let mut span = high;
+        span.0.bit_be_sub(&low.0);
+        match span.0.bit_be_add(&[1]) {
+            true => rng.gen(),
+            false => low + Self::random_below(&span, rng),
+        }
+    }
+}
+
+impl BitsMut<'_> {
+    /// Fills all bytes with random bits
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut entropy = [0u8; 32];
+    /// entropy.bits_mut().fill_random(&mut SmallRng::seed_from_u64(1));
+    /// assert!(!entropy.bits().all_zero());
+    /// ```
+    #[inline(always)]
+    pub fn fill_random<R: RngCore + ?Sized>(&mut self, rng: &mut R) -> &mut Self {
+        rng.fill_bytes(self.0);
+        self
+    }
+}
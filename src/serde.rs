@@ -0,0 +1,312 @@
+//! Serde support, enabled by the `serde` feature
+//!
+//! `NBits` and `UBits` serialize as `0x` hex strings of all their bytes in
+//! human-readable formats, and as fixed-size byte arrays in binary formats.
+//! Strings are parsed as hex (`0x`), binary (`0b`) or decimal literals,
+//! which must fit the width.
+//!
+//! `BitVec` serializes as a string of `0` and `1` in human-readable formats,
+//! and as its bit length followed by its bytes in binary formats.
+//!
+//! # Examples
+//! ```
+//! use nbits::{NBits, UBits};
+//!
+//! let value = NBits([0x01, 0xff]);
+//! assert_eq!(serde_json::to_string(&value).unwrap(), r#""0x01ff""#);
+//! assert_eq!(serde_json::from_str::<NBits<2>>(r#""511""#).unwrap(), value);
+//! assert!(serde_json::from_str::<NBits<1>>(r#""0x1ff""#).is_err());
+//! assert!(serde_json::from_str::<UBits<9, 2>>(r#""0x3ff""#).is_err());
+//!
+//! assert_eq!(bincode::serialize(&value).unwrap(), [0x01, 0xff]);
+//! ```
+
+use crate::{BitVec, NBits, UBits};
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeTuple, Serializer};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// Serialize as decimal strings in human-readable formats, with `#[serde(with)]`
+///
+/// # Examples
+/// ```
+/// use nbits::NBits;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "nbits::serde::decimal")]
+///     limit: NBits<16>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"limit":"1000000"}"#).unwrap();
+/// assert_eq!(config.limit, 1_000_000);
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"limit":"1000000"}"#);
+/// ```
+pub mod decimal {
+    use super::*;
+
+    /// Big-endian bytes as a decimal string, or as `T` does in binary formats
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + AsRef<[u8]>,
+        S: Serializer,
+    {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(&Decimal(value.as_ref())),
+            false => value.serialize(serializer),
+        }
+    }
+
+    /// Same as `T`, which accepts decimal strings already
+    #[inline(always)]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)
+    }
+}
+
+/// Big-endian bytes as `0x` hex
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            [] => f.write_str("0x0"),
+            bytes => {
+                f.write_str("0x")?;
+                bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+            }
+        }
+    }
+}
+
+/// Big-endian bytes as decimal
+struct Decimal<'a>(&'a [u8]);
+
+impl fmt::Display for Decimal<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const BASE: u64 = 1_000_000_000;
+
+        // Divide by 10^9 repeatedly, the groups are from the lowest
+        let mut value = self.0.to_vec();
+        let mut groups = Vec::new();
+        while value.iter().any(|&v| v != 0) {
+            let rem = value.iter_mut().fold(0, |rem, v| {
+                let acc = rem << 8 | *v as u64;
+                *v = (acc / BASE) as u8;
+                acc % BASE
+            });
+            groups.push(rem);
+        }
+
+        match groups.split_last() {
+            None => f.write_str("0"),
+            Some((high, rest)) => {
+                write!(f, "{high}")?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|group| write!(f, "{group:09}"))
+            }
+        }
+    }
+}
+
+impl<const N: usize> Serialize for NBits<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(&Hex(&self.0)),
+            false => {
+                let mut tuple = serializer.serialize_tuple(N)?;
+                self.0
+                    .iter()
+                    .try_for_each(|byte| tuple.serialize_element(byte))?;
+                tuple.end()
+            }
+        }
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for NBits<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(NBitsVisitor),
+            false => deserializer.deserialize_tuple(N, NBitsVisitor),
+        }
+    }
+}
+
+/// Visits literal strings, integers or `N` bytes
+struct NBitsVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for NBitsVisitor<N> {
+    type Value = NBits<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a literal string or {N} bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        NBits::parse_literal(v.as_bytes()).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        NBits(v.to_be_bytes()).resize().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        v.try_into()
+            .map(NBits)
+            .map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut value = NBits::new();
+        for (i, byte) in value.0.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        match seq.next_element::<u8>()? {
+            Some(_) => Err(de::Error::invalid_length(N + 1, &self)),
+            None => Ok(value),
+        }
+    }
+}
+
+impl<const BITS: usize, const N: usize> Serialize for UBits<BITS, N> {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_nbits().serialize(serializer)
+    }
+}
+
+/// Fails if any bit above `BITS` is set
+impl<'de, const BITS: usize, const N: usize> Deserialize<'de> for UBits<BITS, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = NBits::deserialize(deserializer)?;
+        Self::try_from(value).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for BitVec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let bits: String = self.iter().map(|bit| if bit { '1' } else { '0' }).collect();
+                serializer.serialize_str(&bits)
+            }
+            false => {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&(self.len() as u64))?;
+                tuple.serialize_element(&Bytes(self.as_bytes()))?;
+                tuple.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BitVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_str(BitVecVisitor),
+            false => deserializer.deserialize_tuple(2, BitVecVisitor),
+        }
+    }
+}
+
+/// Visits strings of `0` and `1`, or the bit length with the bytes
+struct BitVecVisitor;
+
+impl<'de> Visitor<'de> for BitVecVisitor {
+    type Value = BitVec;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string of `0` and `1`, or the bit length with the bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.chars()
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                c => Err(E::invalid_value(de::Unexpected::Char(c), &self)),
+            })
+            .collect()
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let len: u64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let ByteBuf(bytes) = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        let len = usize::try_from(len).map_err(de::Error::custom)?;
+        if bytes.len() != len.div_ceil(8) {
+            return Err(de::Error::invalid_length(bytes.len(), &self));
+        }
+        let mut bits = BitVec::from(bytes);
+        let padding = bits.len() - len;
+        if bits
+            .as_bytes()
+            .last()
+            .is_some_and(|&v| v & !(0xff << padding) != 0)
+        {
+            return Err(de::Error::custom(crate::Error::NonZeroTrailing {
+                bits: padding,
+            }));
+        }
+        bits.truncate(len);
+        Ok(bits)
+    }
+}
+
+/// Serializes bytes with `serialize_bytes`
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Deserializes bytes from formats with or without a bytes type
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+    type Value = ByteBuf;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(ByteBuf(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(ByteBuf(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(ByteBuf(bytes))
+    }
+}
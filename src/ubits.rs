@@ -0,0 +1,355 @@
+use super::core::{BitArith, Bitwise};
+use crate::nbits::{be_div, be_mul, be_rem};
+use crate::{BitsRef, Error, NBits, XBits};
+
+/**
+ *  `UBits` is an unsigned integer of exactly `BITS` bits, stored
+ *  big-endian in `N` bytes, where `N` must be `BITS.div_ceil(8)`.
+ *  The unused high bits of the first byte are always zero, and
+ *  arithmetic wraps or overflows at `BITS` bits.
+ *
+ * # Examples
+ * ```
+ * use nbits::{NBits, UBits};
+ *
+ * // BIP39 entropy of 128 bits with its 4 bits checksum
+ * type Entropy = UBits<132, 17>;
+ * let max = Entropy::max();
+ * assert_eq!(max.as_bytes()[0], 0x0f);
+ * assert_eq!(max.checked_add(&Entropy::one()), None);
+ * assert_eq!(max.wrapping_add(&Entropy::one()), Entropy::new());
+ * assert!(Entropy::try_from(NBits([0xff; 17])).is_err());
+ * ```
+ */
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UBits<const BITS: usize, const N: usize>(NBits<N>);
+
+impl<const BITS: usize, const N: usize> UBits<BITS, N> {
+    /// The number of bits
+    pub const BITS: usize = BITS;
+
+    /// The unused high bits of the first byte
+    const PAD: usize = N * 8 - BITS;
+
+    /// Creates a new `UBits` instance with all bits set to 0.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::wrap(NBits::new())
+    }
+
+    /// The value 1
+    #[inline]
+    pub const fn one() -> Self {
+        let mut value = Self::new();
+        value.0 .0[N - 1] = 1;
+        value
+    }
+
+    /// The largest value, all `BITS` bits set to 1
+    #[inline]
+    pub const fn max() -> Self {
+        Self::from_nbits_wrapping(NBits([0xff; N]))
+    }
+
+    /// Creates from `NBits`, the bits above `BITS` are dropped.
+    /// # Examples
+    /// ```
+    /// # use nbits::{NBits, UBits};
+    /// let value = UBits::<12, 2>::from_nbits_wrapping(NBits([0xab, 0xcd]));
+    /// assert_eq!(value.as_bytes(), [0x0b, 0xcd]);
+    /// ```
+    #[inline]
+    pub const fn from_nbits_wrapping(value: NBits<N>) -> Self {
+        let mut value = Self::wrap(value);
+        value.mask();
+        value
+    }
+
+    /// The underlying `NBits`
+    #[inline(always)]
+    pub const fn as_nbits(&self) -> &NBits<N> {
+        &self.0
+    }
+
+    /// Unwraps the underlying `NBits`.
+    #[inline(always)]
+    pub const fn into_nbits(self) -> NBits<N> {
+        self.0
+    }
+
+    /// The big-endian bytes, the unused high bits of the first byte are zero
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.0 .0
+    }
+
+    /// Bit operations view of the bytes, including the unused high bits of the first byte
+    #[inline(always)]
+    pub fn bits(&self) -> BitsRef<'_> {
+        self.0.bits()
+    }
+
+    /// Bit at `index` of `BITS` bits, 0 is the most significant bit
+    #[inline(always)]
+    pub const fn bit(&self, index: usize) -> bool {
+        assert!(index < BITS, "[nbits] Index out of bounds");
+        self.0.bit(index + Self::PAD)
+    }
+
+    /// Number of leading zeros of `BITS` bits
+    #[inline(always)]
+    pub fn leading_zeros(&self) -> usize {
+        self.0 .0.bit_leading_zeros() - Self::PAD
+    }
+
+    #[inline(always)]
+    pub fn count_ones(&self) -> usize {
+        self.0 .0.bit_count_ones()
+    }
+
+    /// `self + other` modulo `2^BITS`
+    #[inline]
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        self.overflowing(other, <[u8]>::bit_be_add).0
+    }
+
+    /// `self - other` modulo `2^BITS`
+    #[inline]
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        self.overflowing(other, <[u8]>::bit_be_sub).0
+    }
+
+    /// `self * other` modulo `2^BITS`
+    #[inline]
+    pub fn wrapping_mul(&self, other: &Self) -> Self {
+        self.overflowing(other, be_mul::<N>).0
+    }
+
+    /// `self << n` dropping the bits shifted above `BITS`
+    #[inline]
+    pub fn wrapping_shl(&self, n: usize) -> Self {
+        let mut value = self.clone();
+        value.0 .0.bit_shl(n);
+        value.mask();
+        value
+    }
+
+    /// `self + other`, `None` if overflow `BITS` bits
+    #[inline]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.checked(other, <[u8]>::bit_be_add)
+    }
+
+    /// `self - other`, `None` if `other` is greater
+    #[inline]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked(other, <[u8]>::bit_be_sub)
+    }
+
+    /// `self * other`, `None` if overflow `BITS` bits
+    #[inline]
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        self.checked(other, be_mul::<N>)
+    }
+
+    /// `self / other`, `None` if `other` is zero
+    #[inline]
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked(other, be_div::<N>)
+    }
+
+    /// `self % other`, `None` if `other` is zero
+    #[inline]
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        self.checked(other, be_rem::<N>)
+    }
+
+    /// Wrap `NBits` as is, every constructor checks the byte count at compile time here
+    #[inline(always)]
+    const fn wrap(value: NBits<N>) -> Self {
+        const {
+            assert!(
+                BITS > 0 && N == BITS.div_ceil(8),
+                "[nbits] UBits<BITS, N> requires BITS > 0 and N == BITS.div_ceil(8)"
+            )
+        };
+        Self(value)
+    }
+
+    /// Clear the unused high bits, return whether any was set
+    #[inline(always)]
+    const fn mask(&mut self) -> bool {
+        match (N, Self::PAD) {
+            (0, _) | (_, 0) => false,
+            _ => {
+                let high = self.0 .0[0] & !(0xff >> Self::PAD);
+                self.0 .0[0] ^= high;
+                high != 0
+            }
+        }
+    }
+
+    /// Apply an arithmetic operation, return the result wrapped at `BITS` and whether overflow
+    #[inline(always)]
+    fn overflowing(&self, other: &Self, op: fn(&mut [u8], &[u8]) -> bool) -> (Self, bool) {
+        let mut value = self.clone();
+        let overflow = op(&mut value.0 .0, &other.0 .0);
+        let truncated = value.mask();
+        (value, overflow | truncated)
+    }
+
+    #[inline(always)]
+    fn checked(&self, other: &Self, op: fn(&mut [u8], &[u8]) -> bool) -> Option<Self> {
+        match self.overflowing(other, op) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+}
+
+impl<const BITS: usize, const N: usize> Default for UBits<BITS, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize, const N: usize> AsRef<[u8]> for UBits<BITS, N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const BITS: usize, const N: usize> From<UBits<BITS, N>> for NBits<N> {
+    #[inline(always)]
+    fn from(value: UBits<BITS, N>) -> Self {
+        value.0
+    }
+}
+
+/// Fails with `Error::WidthOverflow` if any bit above `BITS` is set
+impl<const BITS: usize, const N: usize> TryFrom<NBits<N>> for UBits<BITS, N> {
+    type Error = Error;
+
+    fn try_from(value: NBits<N>) -> Result<Self, Error> {
+        match value.0.bit_leading_zeros() {
+            zeros if zeros < Self::PAD => Err(Error::WidthOverflow {
+                bits: N * 8 - zeros,
+                width: BITS,
+            }),
+            _ => Ok(Self::wrap(value)),
+        }
+    }
+}
+
+impl<const BITS: usize, const N: usize> core::fmt::Display for UBits<BITS, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Arithmetic operators panic on overflow of `BITS` bits, as `NBits`
+macro_rules! ubits_arith {
+    ($($op: ident, $fn: ident, $op_assign: ident, $fn_assign: ident, $arith: expr;)*) => {$(
+        impl<const BITS: usize, const N: usize> core::ops::$op<&UBits<BITS, N>> for UBits<BITS, N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, other: &Self) -> Self::Output {
+                let (value, overflow) = self.overflowing(other, $arith);
+                assert!(!overflow, concat!("[nbits] Overflow in `", stringify!($fn), "`"));
+                value
+            }
+        }
+
+        impl<const BITS: usize, const N: usize> core::ops::$op_assign<&UBits<BITS, N>> for UBits<BITS, N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: &Self) {
+                let overflow;
+                (*self, overflow) = self.overflowing(other, $arith);
+                assert!(!overflow, concat!("[nbits] Overflow in `", stringify!($fn_assign), "`"));
+            }
+        }
+    )*};
+}
+
+ubits_arith! {
+    Add, add, AddAssign, add_assign, <[u8]>::bit_be_add;
+    Sub, sub, SubAssign, sub_assign, <[u8]>::bit_be_sub;
+    Mul, mul, MulAssign, mul_assign, be_mul::<N>;
+    Div, div, DivAssign, div_assign, be_div::<N>;
+    Rem, rem, RemAssign, rem_assign, be_rem::<N>;
+}
+
+/// Bitwise operators keep the unused high bits zero
+macro_rules! ubits_bitwise {
+    ($($op: ident, $fn: ident, $op_assign: ident, $fn_assign: ident, $bitwise: ident;)*) => {$(
+        impl<const BITS: usize, const N: usize> core::ops::$op<&UBits<BITS, N>> for UBits<BITS, N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(mut self, other: &Self) -> Self::Output {
+                self.0 .0.$bitwise(&other.0 .0);
+                self
+            }
+        }
+
+        impl<const BITS: usize, const N: usize> core::ops::$op_assign<&UBits<BITS, N>> for UBits<BITS, N> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, other: &Self) {
+                self.0 .0.$bitwise(&other.0 .0);
+            }
+        }
+    )*};
+}
+
+ubits_bitwise! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, bit_be_and;
+    BitOr, bitor, BitOrAssign, bitor_assign, bit_be_or;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, bit_be_xor;
+}
+
+impl<const BITS: usize, const N: usize> core::ops::Not for UBits<BITS, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(mut self) -> Self::Output {
+        self.0 .0.bit_not();
+        self.mask();
+        self
+    }
+}
+
+impl<const BITS: usize, const N: usize> core::ops::Shl<usize> for UBits<BITS, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: usize) -> Self::Output {
+        self.wrapping_shl(rhs)
+    }
+}
+
+impl<const BITS: usize, const N: usize> core::ops::Shr<usize> for UBits<BITS, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(mut self, rhs: usize) -> Self::Output {
+        self.0 .0.bit_shr(rhs);
+        self
+    }
+}
+
+impl<const BITS: usize, const N: usize> core::ops::ShlAssign<usize> for UBits<BITS, N> {
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = self.wrapping_shl(rhs);
+    }
+}
+
+impl<const BITS: usize, const N: usize> core::ops::ShrAssign<usize> for UBits<BITS, N> {
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: usize) {
+        self.0 .0.bit_shr(rhs);
+    }
+}
@@ -0,0 +1,334 @@
+//! Byte-oriented variable-length integers
+//!
+//! Varints are read from any [`BitRead`] and written to any [`BitWrite`] 8 bits a byte,
+//! such as [`BitCursor`](crate::core::BitCursor) over a byte buffer
+//! or [`BitReader`](crate::io::BitReader) over a stream.
+//! Decoding rejects non-minimal encodings with `Error::NonMinimal`,
+//! and values overflow the decoded type with `Error::InvalidCode`.
+//!
+//! # Examples
+//! ```
+//! use nbits::core::{BitCursor, BitCursorMut};
+//! use nbits::varint::{VarintRead, VarintWrite};
+//!
+//! let mut data = [0_u8; 9];
+//! let mut cursor = BitCursorMut::new(&mut data);
+//! cursor.write_uleb128(624_485).unwrap();
+//! cursor.write_sleb128(-123_456).unwrap();
+//! cursor.write_compact_size(515).unwrap();
+//! assert_eq!(cursor.position(), 9 * 8);
+//! assert_eq!(data, [0xe5, 0x8e, 0x26, 0xc0, 0xbb, 0x78, 0xfd, 0x03, 0x02]);
+//! ```
+
+use crate::core::{BitField, BitRead, BitWrite, Bitwise};
+use crate::{Error, NBits};
+
+/**
+ * Varint decoders for any bit source
+ */
+pub trait VarintRead: BitRead {
+    /// Read unsigned LEB128
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursor;
+    /// # use nbits::varint::VarintRead;
+    /// assert_eq!(BitCursor::new(&[0xe5, 0x8e, 0x26]).read_uleb128(), Ok(624_485));
+    /// assert!(BitCursor::new(&[0xe5, 0x8e, 0x26, 0x00]).read_uleb128().is_ok());
+    /// assert!(BitCursor::new(&[0xe5, 0x8e, 0xa6, 0x00]).read_uleb128().is_err());
+    /// ```
+    fn read_uleb128(&mut self) -> Result<u64, Self::Error> {
+        let mut buf = [0; 8];
+        read_leb128(self, &mut buf, false)?;
+        Ok(u64::from_be_bytes(buf))
+    }
+
+    /// Read signed LEB128
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursor;
+    /// # use nbits::varint::VarintRead;
+    /// assert_eq!(BitCursor::new(&[0xc0, 0xbb, 0x78]).read_sleb128(), Ok(-123_456));
+    /// assert!(BitCursor::new(&[0xff, 0x7f]).read_sleb128().is_err());
+    /// ```
+    fn read_sleb128(&mut self) -> Result<i64, Self::Error> {
+        let mut buf = [0; 8];
+        read_leb128(self, &mut buf, true)?;
+        Ok(i64::from_be_bytes(buf))
+    }
+
+    /// Read unsigned LEB128 of any width
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursor;
+    /// # use nbits::varint::VarintRead;
+    /// # use nbits::NBits;
+    /// let mut data = [0xff; 19];
+    /// data[18] = 0x03;
+    /// let value = BitCursor::new(&data).read_uleb128_nbits::<16>();
+    /// assert_eq!(value, Ok(NBits([0xff; 16])));
+    /// ```
+    fn read_uleb128_nbits<const N: usize>(&mut self) -> Result<NBits<N>, Self::Error> {
+        let mut value = NBits::<N>::new();
+        read_leb128(self, &mut value.0, false)?;
+        Ok(value)
+    }
+
+    /// Read signed LEB128 of any width, as two's complement
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursor;
+    /// # use nbits::varint::VarintRead;
+    /// # use nbits::NBits;
+    /// let value = BitCursor::new(&[0x7f]).read_sleb128_nbits::<16>();
+    /// assert_eq!(value, Ok(NBits([0xff; 16])));
+    /// ```
+    fn read_sleb128_nbits<const N: usize>(&mut self) -> Result<NBits<N>, Self::Error> {
+        let mut value = NBits::<N>::new();
+        read_leb128(self, &mut value.0, true)?;
+        Ok(value)
+    }
+
+    /// Read Bitcoin CompactSize
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursor;
+    /// # use nbits::varint::VarintRead;
+    /// # use nbits::Error;
+    /// assert_eq!(BitCursor::new(&[0xfc]).read_compact_size(), Ok(0xfc));
+    /// assert_eq!(BitCursor::new(&[0xfd, 0x03, 0x02]).read_compact_size(), Ok(515));
+    /// assert_eq!(
+    ///     BitCursor::new(&[0xfd, 0xfc, 0x00]).read_compact_size(),
+    ///     Err(Error::NonMinimal)
+    /// );
+    /// ```
+    fn read_compact_size(&mut self) -> Result<u64, Self::Error> {
+        let (len, min) = match self.read_bits(8)? {
+            prefix @ 0..=0xfc => return Ok(prefix),
+            0xfd => (2, 0xfd),
+            0xfe => (4, 0x1_0000),
+            _ => (8, 0x1_0000_0000),
+        };
+        let mut buf = [0; 8];
+        for byte in &mut buf[..len] {
+            *byte = self.read_bits(8)? as u8;
+        }
+        match u64::from_le_bytes(buf) {
+            value if value < min => Err(Error::NonMinimal.into()),
+            value => Ok(value),
+        }
+    }
+}
+
+impl<S: BitRead + ?Sized> VarintRead for S {}
+
+/**
+ * Varint encoders for any bit sink, encodings are always minimal
+ */
+pub trait VarintWrite: BitWrite {
+    /// Write unsigned LEB128
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursorMut;
+    /// # use nbits::varint::VarintWrite;
+    /// let mut data = [0_u8; 3];
+    /// BitCursorMut::new(&mut data).write_uleb128(624_485).unwrap();
+    /// assert_eq!(data, [0xe5, 0x8e, 0x26]);
+    /// ```
+    fn write_uleb128(&mut self, value: u64) -> Result<(), Self::Error> {
+        write_leb128(self, &value.to_be_bytes(), false)
+    }
+
+    /// Write signed LEB128
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursorMut;
+    /// # use nbits::varint::VarintWrite;
+    /// let mut data = [0_u8; 3];
+    /// BitCursorMut::new(&mut data).write_sleb128(-123_456).unwrap();
+    /// assert_eq!(data, [0xc0, 0xbb, 0x78]);
+    /// ```
+    fn write_sleb128(&mut self, value: i64) -> Result<(), Self::Error> {
+        write_leb128(self, &value.to_be_bytes(), true)
+    }
+
+    /// Write unsigned LEB128 of any width
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursorMut;
+    /// # use nbits::varint::VarintWrite;
+    /// # use nbits::NBits;
+    /// let mut data = [0_u8; 19];
+    /// BitCursorMut::new(&mut data).write_uleb128_nbits(&NBits([0xff; 16])).unwrap();
+    /// assert_eq!(data[..18], [0xff; 18]);
+    /// assert_eq!(data[18], 0x03);
+    /// ```
+    fn write_uleb128_nbits<const N: usize>(&mut self, value: &NBits<N>) -> Result<(), Self::Error> {
+        write_leb128(self, &value.0, false)
+    }
+
+    /// Write signed LEB128 of any width, from two's complement
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursorMut;
+    /// # use nbits::varint::VarintWrite;
+    /// # use nbits::NBits;
+    /// let mut data = [0_u8; 1];
+    /// BitCursorMut::new(&mut data).write_sleb128_nbits(&NBits([0xff; 16])).unwrap();
+    /// assert_eq!(data, [0x7f]);
+    /// ```
+    fn write_sleb128_nbits<const N: usize>(&mut self, value: &NBits<N>) -> Result<(), Self::Error> {
+        write_leb128(self, &value.0, true)
+    }
+
+    /// Write Bitcoin CompactSize
+    /// # Examples
+    /// ```
+    /// # use nbits::core::BitCursorMut;
+    /// # use nbits::varint::VarintWrite;
+    /// let mut data = [0_u8; 4];
+    /// let mut cursor = BitCursorMut::new(&mut data);
+    /// cursor.write_compact_size(0xfc).unwrap();
+    /// cursor.write_compact_size(515).unwrap();
+    /// assert_eq!(data, [0xfc, 0xfd, 0x03, 0x02]);
+    /// ```
+    fn write_compact_size(&mut self, value: u64) -> Result<(), Self::Error> {
+        let (prefix, len) = match value {
+            0..=0xfc => return self.write_bits(value, 8),
+            0xfd..=0xffff => (0xfd, 2),
+            0x1_0000..=0xffff_ffff => (0xfe, 4),
+            _ => (0xff, 8),
+        };
+        self.write_bits(prefix, 8)?;
+        let bytes = value.to_le_bytes();
+        bytes[..len]
+            .iter()
+            .try_for_each(|&byte| self.write_bits(byte as u64, 8))
+    }
+}
+
+impl<S: BitWrite + ?Sized> VarintWrite for S {}
+
+/// Map signed to unsigned so that small magnitudes stay small, as Protobuf `sint64`
+/// # Examples
+/// ```
+/// # use nbits::varint::zigzag_encode;
+/// assert_eq!(zigzag_encode(0), 0);
+/// assert_eq!(zigzag_encode(-1), 1);
+/// assert_eq!(zigzag_encode(1), 2);
+/// assert_eq!(zigzag_encode(i64::MIN), u64::MAX);
+/// ```
+#[inline(always)]
+pub const fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Inverse of [`zigzag_encode`]
+/// # Examples
+/// ```
+/// # use nbits::varint::zigzag_decode;
+/// assert_eq!(zigzag_decode(1), -1);
+/// assert_eq!(zigzag_decode(u64::MAX - 1), i64::MAX);
+/// ```
+#[inline(always)]
+pub const fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Payload bits of a LEB128 byte
+const GROUP_BITS: usize = 7;
+/// Continuation bit of a LEB128 byte
+const CONTINUE: u8 = 0x80;
+
+/// 7 bits group of a big-endian value at `shift` bits from the lowest bit,
+/// bits above the value are `fill`
+fn group_at(value: &[u8], shift: usize, fill: bool) -> Result<u8, Error> {
+    let bits = value.len() * 8;
+    let width = bits.saturating_sub(shift).min(GROUP_BITS);
+    let high = match fill {
+        true => 0x7f << width & 0x7f,
+        false => 0,
+    };
+    match width {
+        0 => Ok(high),
+        _ => Ok(value.bit_be_field(bits - shift - width, width)? as u8 | high),
+    }
+}
+
+/// Write LEB128 of a big-endian value, two's complement if `signed`
+fn write_leb128<S: BitWrite + ?Sized>(
+    sink: &mut S,
+    value: &[u8],
+    signed: bool,
+) -> Result<(), S::Error> {
+    let bits = value.len() * 8;
+    let negative = signed && value.first().is_some_and(|&byte| byte & 0x80 != 0);
+    // significant bits, with the sign bit if signed
+    let significant = match negative {
+        true => bits - value.bit_leading_ones() + 1,
+        false => bits - value.bit_leading_zeros() + signed as usize,
+    };
+    let groups = significant.div_ceil(GROUP_BITS).max(1);
+    for i in 0..groups {
+        let group = group_at(value, i * GROUP_BITS, negative)?;
+        let more = match i + 1 < groups {
+            true => CONTINUE,
+            false => 0,
+        };
+        sink.write_bits((group | more) as u64, 8)?;
+    }
+    Ok(())
+}
+
+/// Read LEB128 into a zeroed big-endian buffer, two's complement if `signed`
+fn read_leb128<S: BitRead + ?Sized>(
+    source: &mut S,
+    buf: &mut [u8],
+    signed: bool,
+) -> Result<(), S::Error> {
+    let bits = buf.len() * 8;
+    let max_groups = bits.div_ceil(GROUP_BITS).max(1);
+    // value bits from `limit` up must all be the sign bit, or zero if unsigned
+    let limit = bits - (signed && bits > 0) as usize;
+    let (mut high_ones, mut high_zeros) = (false, false);
+    let mut prev = 0_u8;
+    for i in 0..max_groups {
+        let byte = source.read_bits(8)? as u8;
+        let group = byte & !CONTINUE;
+        let shift = i * GROUP_BITS;
+        let width = bits.saturating_sub(shift).min(GROUP_BITS);
+        if width > 0 {
+            let low = group & !(0x7f << width);
+            buf.bit_be_set_field(bits - shift - width, width, low as u128)?;
+        }
+        let kept = limit.saturating_sub(shift).min(GROUP_BITS);
+        if kept < GROUP_BITS {
+            high_ones |= group >> kept != 0;
+            high_zeros |= group >> kept != 0x7f >> kept;
+        }
+        if byte & CONTINUE != 0 {
+            prev = group;
+            continue;
+        }
+
+        let negative = signed && group & 0x40 != 0;
+        let redundant = match signed {
+            true => (group == 0 && prev & 0x40 == 0) || (group == 0x7f && prev & 0x40 != 0),
+            false => group == 0,
+        };
+        if i > 0 && redundant {
+            return Err(Error::NonMinimal.into());
+        }
+        if (negative && high_zeros) || (!negative && high_ones) {
+            return Err(Error::InvalidCode.into());
+        }
+        // sign extension above the last group
+        let (mut offset, end) = (0, bits.saturating_sub(shift + GROUP_BITS));
+        while negative && offset < end {
+            let width = (end - offset).min(u128::BITS as usize);
+            buf.bit_be_set_field(offset, width, u128::MAX >> (u128::BITS as usize - width))?;
+            offset += width;
+        }
+        return Ok(());
+    }
+    Err(Error::InvalidCode.into())
+}
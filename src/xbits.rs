@@ -1,4 +1,8 @@
-use super::core::{BitIterator, Bitwise};
+use super::core::{
+    BitArith, BitChunks, BitField, BitFill, BitIterator, BitPositions, Bitwise, ChunkPadding,
+    LengthPolicy,
+};
+use crate::{Error, Numeric};
 
 /**
  * `XBits` trait provides a way to work with bit-level operations on byte arrays.
@@ -6,15 +10,15 @@ use super::core::{BitIterator, Bitwise};
  * such as checking if all bits are one or zero, and iterating over the bits.
  */
 pub trait XBits {
-    fn bits(&self) -> BitsRef;
-    fn bits_mut(&mut self) -> BitsMut;
+    fn bits(&self) -> BitsRef<'_>;
+    fn bits_mut(&mut self) -> BitsMut<'_>;
 }
 
 impl XBits for [u8] {
-    fn bits(&self) -> BitsRef {
+    fn bits(&self) -> BitsRef<'_> {
         BitsRef(self)
     }
-    fn bits_mut(&mut self) -> BitsMut {
+    fn bits_mut(&mut self) -> BitsMut<'_> {
         BitsMut(self)
     }
 }
@@ -23,7 +27,7 @@ impl XBits for [u8] {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BitsRef<'a>(pub &'a [u8]);
 
-impl BitsRef<'_> {
+impl<'a> BitsRef<'a> {
     #[inline(always)]
     pub fn as_bytes(&self) -> &[u8] {
         self.0
@@ -49,18 +53,175 @@ impl BitsRef<'_> {
         self.0.bit_trailing_zeros()
     }
 
+    #[inline(always)]
+    pub fn leading_ones(&self) -> usize {
+        self.0.bit_leading_ones()
+    }
+
+    #[inline(always)]
+    pub fn trailing_ones(&self) -> usize {
+        self.0.bit_trailing_ones()
+    }
+
+    /// Position of the first `1` bit
+    #[inline(always)]
+    pub fn first_one(&self) -> Option<usize> {
+        self.0.bit_next_one(0)
+    }
+
+    /// Position of the last `1` bit
+    #[inline(always)]
+    pub fn last_one(&self) -> Option<usize> {
+        self.0.bit_prev_one(usize::MAX)
+    }
+
+    /// Position of the first `1` bit at or after `from`
+    #[inline(always)]
+    pub fn next_one(&self, from: usize) -> Option<usize> {
+        self.0.bit_next_one(from)
+    }
+
+    /// Position of the last `1` bit at or before `from`
+    #[inline(always)]
+    pub fn prev_one(&self, from: usize) -> Option<usize> {
+        self.0.bit_prev_one(from)
+    }
+
+    /// Position of the first `0` bit
+    #[inline(always)]
+    pub fn first_zero(&self) -> Option<usize> {
+        self.0.bit_next_zero(0)
+    }
+
+    /// Position of the last `0` bit
+    #[inline(always)]
+    pub fn last_zero(&self) -> Option<usize> {
+        self.0.bit_prev_zero(usize::MAX)
+    }
+
+    /// Position of the first `0` bit at or after `from`
+    #[inline(always)]
+    pub fn next_zero(&self, from: usize) -> Option<usize> {
+        self.0.bit_next_zero(from)
+    }
+
+    /// Position of the last `0` bit at or before `from`
+    #[inline(always)]
+    pub fn prev_zero(&self, from: usize) -> Option<usize> {
+        self.0.bit_prev_zero(from)
+    }
+
     #[inline(always)]
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
         self.0.bit_iter()
     }
 
     #[inline(always)]
-    pub fn chunks<T>(&self, n: usize) -> impl std::iter::Iterator<Item = T> + '_
+    pub fn count_ones(&self) -> usize {
+        self.0.bit_count_ones()
+    }
+
+    #[inline(always)]
+    pub fn count_zeros(&self) -> usize {
+        self.0.bit_count_zeros()
+    }
+
+    /// Numeric comparison of big-endian values, whatever the lengths
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!([0x00, 0x01, 0x00].bits().numeric_cmp([0xff].bits()), Ordering::Greater);
+    /// assert_eq!([0x00, 0xff].bits().numeric_cmp([0xff].bits()), Ordering::Equal);
+    /// ```
+    #[inline(always)]
+    pub fn numeric_cmp(&self, other: BitsRef) -> core::cmp::Ordering {
+        self.0.bit_be_cmp(other.0)
+    }
+
+    /// Numeric comparison of little-endian values, whatever the lengths
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!([0x00, 0x01, 0x00].bits().numeric_le_cmp([0xff].bits()), Ordering::Greater);
+    /// assert_eq!([0xff, 0x00].bits().numeric_le_cmp([0xff].bits()), Ordering::Equal);
+    /// ```
+    #[inline(always)]
+    pub fn numeric_le_cmp(&self, other: BitsRef) -> core::cmp::Ordering {
+        self.0.bit_le_cmp(other.0)
+    }
+
+    /// Numeric view of the big-endian value
+    #[inline(always)]
+    pub fn numeric(&self) -> Numeric<'a> {
+        Numeric::Be(self.0)
+    }
+
+    /// Numeric view of the little-endian value
+    #[inline(always)]
+    pub fn le_numeric(&self) -> Numeric<'a> {
+        Numeric::Le(self.0)
+    }
+
+    /// Iterator positions of `1` bits
+    #[inline(always)]
+    pub fn iter_ones(&self) -> BitPositions<'a> {
+        self.0.bit_iter_ones()
+    }
+
+    /// Iterator positions of `0` bits
+    #[inline(always)]
+    pub fn iter_zeros(&self) -> BitPositions<'a> {
+        self.0.bit_iter_zeros()
+    }
+
+    /// Extract big-endian bit field of `width` bits at `offset`
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// assert_eq!([0x12, 0x34, 0x56].bits().get_field::<u16>(4, 16), Ok(0x2345));
+    /// assert!([0x12, 0x34, 0x56].bits().get_field::<u8>(4, 16).is_err());
+    /// ```
+    #[inline(always)]
+    pub fn get_field<T: TryFrom<u128>>(&self, offset: usize, width: usize) -> Result<T, Error> {
+        field_into(self.0.bit_be_field(offset, width)?)
+    }
+
+    /// Extract little-endian bit field of `width` bits at `offset`
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// assert_eq!([0x34, 0x12].bits().get_le_field::<u16>(0, 16), Ok(0x1234));
+    /// ```
+    #[inline(always)]
+    pub fn get_le_field<T: TryFrom<u128>>(&self, offset: usize, width: usize) -> Result<T, Error> {
+        field_into(self.0.bit_le_field(offset, width)?)
+    }
+
+    #[inline(always)]
+    pub fn chunks<T>(&self, n: usize) -> BitChunks<'a, T>
     where
-        T: TryFrom<u64> + Default + 'static,
+        T: TryFrom<u128> + Default,
     {
         self.0.bit_chunks(n)
     }
+
+    #[inline(always)]
+    pub fn chunks_with<T>(&self, n: usize, padding: ChunkPadding) -> Result<BitChunks<'a, T>, Error>
+    where
+        T: TryFrom<u128> + Default,
+    {
+        self.0.bit_chunks_with(n, padding)
+    }
+
+    #[inline(always)]
+    pub fn try_chunks<T>(&self, n: usize) -> impl core::iter::Iterator<Item = Result<T, Error>> + '_
+    where
+        T: TryFrom<u128> + 'static,
+    {
+        self.0.try_bit_chunks(n)
+    }
 }
 
 /// A mutable reference to a byte array that allows for bit-level operations.
@@ -69,7 +230,7 @@ pub struct BitsMut<'a>(pub &'a mut [u8]);
 
 impl BitsMut<'_> {
     #[inline(always)]
-    pub fn to_ref(&self) -> BitsRef {
+    pub fn to_ref(&self) -> BitsRef<'_> {
         BitsRef(self.0)
     }
 
@@ -103,6 +264,32 @@ impl BitsMut<'_> {
         self
     }
 
+    /// Bitwise `|` with length policy, returns `true` if significant bits of `other` are lost
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// # use nbits::core::LengthPolicy;
+    /// let mut data = [0b0000_1111];
+    /// assert_eq!(data.bits_mut().try_or([0b0000_0001, 0b1111_0000].bits(), LengthPolicy::Truncate), Ok(true));
+    /// assert_eq!(data, [0b1111_1111]);
+    /// ```
+    #[inline(always)]
+    pub fn try_or(&mut self, other: BitsRef, policy: LengthPolicy) -> Result<bool, Error> {
+        self.0.try_bit_be_or(other.0, policy)
+    }
+
+    /// Bitwise `&` with length policy, returns `true` if significant bits of `other` are lost
+    #[inline(always)]
+    pub fn try_and(&mut self, other: BitsRef, policy: LengthPolicy) -> Result<bool, Error> {
+        self.0.try_bit_be_and(other.0, policy)
+    }
+
+    /// Bitwise `^` with length policy, returns `true` if significant bits of `other` are lost
+    #[inline(always)]
+    pub fn try_xor(&mut self, other: BitsRef, policy: LengthPolicy) -> Result<bool, Error> {
+        self.0.try_bit_be_xor(other.0, policy)
+    }
+
     #[inline(always)]
     pub fn be_or<U: Into<u64>>(&mut self, other: U) -> &mut Self {
         self.0.bit_be_or(&other.into().to_be_bytes());
@@ -121,6 +308,110 @@ impl BitsMut<'_> {
         self
     }
 
+    /// Insert big-endian bit field of `width` bits at `offset`
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// let mut data = [0_u8; 3];
+    /// assert_eq!(data.bits_mut().set_field(4, 16, 0x2345_u16), Ok(()));
+    /// assert_eq!(data, [0x02, 0x34, 0x50]);
+    /// ```
+    #[inline(always)]
+    pub fn set_field<U: Into<u128>>(
+        &mut self,
+        offset: usize,
+        width: usize,
+        value: U,
+    ) -> Result<(), Error> {
+        self.0.bit_be_set_field(offset, width, value.into())
+    }
+
+    /// Insert little-endian bit field of `width` bits at `offset`
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// let mut data = [0_u8; 2];
+    /// assert_eq!(data.bits_mut().set_le_field(0, 16, 0x1234_u16), Ok(()));
+    /// assert_eq!(data, [0x34, 0x12]);
+    /// ```
+    #[inline(always)]
+    pub fn set_le_field<U: Into<u128>>(
+        &mut self,
+        offset: usize,
+        width: usize,
+        value: U,
+    ) -> Result<(), Error> {
+        self.0.bit_le_set_field(offset, width, value.into())
+    }
+
+    /// Copy `len` bits at `src_offset` in `src` to `dst_offset`
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// let mut data = [0_u8; 3];
+    /// let other = [0x12, 0x34];
+    /// assert_eq!(data.bits_mut().copy_bits_from(4, other.bits(), 4, 12), Ok(()));
+    /// assert_eq!(data, [0x02, 0x34, 0x00]);
+    /// ```
+    #[inline(always)]
+    pub fn copy_bits_from(
+        &mut self,
+        dst_offset: usize,
+        src: BitsRef,
+        src_offset: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        self.0.bit_copy_from(dst_offset, src.0, src_offset, len)
+    }
+
+    /// Copy `len` bits at `src_offset` to `dst_offset`, the two ranges may overlap
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// let mut data = [0x12, 0x34, 0x00];
+    /// assert_eq!(data.bits_mut().copy_within_bits(4, 0, 16), Ok(()));
+    /// assert_eq!(data, [0x11, 0x23, 0x40]);
+    /// ```
+    #[inline(always)]
+    pub fn copy_within_bits(
+        &mut self,
+        dst_offset: usize,
+        src_offset: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        self.0.bit_copy_within(dst_offset, src_offset, len)
+    }
+
+    /// Write bool values from the first bit, return the number of bits written
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// let mut data = [0xff; 2];
+    /// assert_eq!(data.bits_mut().fill_bits([true, false, true].into_iter()), Ok(3));
+    /// assert_eq!(data, [0b1010_0000, 0xff]);
+    /// ```
+    #[inline(always)]
+    pub fn fill_bits<U: Iterator<Item = bool>>(&mut self, bits: U) -> Result<usize, Error> {
+        self.0.bit_fill(bits)
+    }
+
+    /// Conjoin the n bits of each value from the first bit, return the number of bits written
+    /// # Examples
+    /// ```
+    /// # use nbits::XBits;
+    /// let mut data = [0xff; 2];
+    /// assert_eq!(data.bits_mut().fill_chunks([0b11_1111_u8, 0b10].into_iter(), 6), Ok(12));
+    /// assert_eq!(data, [0b1111_1100, 0b0010_0000]);
+    /// ```
+    #[inline(always)]
+    pub fn fill_chunks<T, U>(&mut self, chunks: U, n: usize) -> Result<usize, Error>
+    where
+        T: Into<u128>,
+        U: Iterator<Item = T>,
+    {
+        self.0.bit_fill_chunk(chunks, n)
+    }
+
     #[inline(always)]
     pub fn not(&mut self) -> &mut Self {
         self.0.bit_not();
@@ -134,27 +425,39 @@ impl BitsMut<'_> {
     }
 }
 
-impl std::fmt::Display for BitsRef<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        for byte in self.0.iter() {
-            s.push_str(&format!("{:08b} ", byte));
+/// Convert field value to the target type
+#[inline(always)]
+fn field_into<T: TryFrom<u128>>(value: u128) -> Result<T, Error> {
+    value.try_into().map_err(|_| Error::ValueOverflow {
+        value,
+        width: core::mem::size_of::<T>() * 8,
+    })
+}
+
+/// Bytes in binary separated by spaces
+fn fmt_bytes(bytes: &[u8], f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    for (i, byte) in bytes.iter().enumerate() {
+        match i {
+            0 => write!(f, "{:08b}", byte)?,
+            _ => write!(f, " {:08b}", byte)?,
         }
-        write!(f, "{}", s.trim())
     }
+    Ok(())
 }
 
-impl std::fmt::Display for BitsMut<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        for byte in self.0.iter() {
-            s.push_str(&format!("{:08b} ", byte));
-        }
-        write!(f, "{}", s.trim())
+impl core::fmt::Display for BitsRef<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_bytes(self.0, f)
+    }
+}
+
+impl core::fmt::Display for BitsMut<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_bytes(self.0, f)
     }
 }
 
-impl std::ops::Index<usize> for BitsRef<'_> {
+impl core::ops::Index<usize> for BitsRef<'_> {
     type Output = bool;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use nbits::{Error, XBits};
+
+#[test]
+fn test_bits_field() {
+    // 3 bits version, 13 bits length, 8 bits flags
+    let header = [0b1010_0000, 0b0110_1001, 0b1100_0011];
+    let bits = header.bits();
+    assert_eq!(bits.get_field::<u8>(0, 3), Ok(0b101));
+    assert_eq!(bits.get_field::<u16>(3, 13), Ok(0b0_0000_0110_1001));
+    assert_eq!(bits.get_field::<u8>(16, 8), Ok(0b1100_0011));
+    assert_eq!(bits.get_field::<u32>(0, 24), Ok(0xa069c3));
+
+    let mut data = [0_u8; 3];
+    let mut bits = data.bits_mut();
+    bits.set_field(0, 3, 0b101_u8).unwrap();
+    bits.set_field(3, 13, 0b0_0000_0110_1001_u16).unwrap();
+    bits.set_field(16, 8, 0b1100_0011_u8).unwrap();
+    assert_eq!(data, header);
+}
+
+#[test]
+fn test_bits_field_roundtrip() {
+    let mut data = [0xa5_u8; 20];
+    for offset in 0..24 {
+        for width in [1, 7, 8, 9, 13, 57, 64, 100, 128] {
+            let value = u128::MAX >> (128 - width);
+            let value = value & 0x5a5a_5a5a_5a5a_5a5a_5a5a_5a5a_5a5a_5a5a;
+            data.bits_mut().set_field(offset, width, value).unwrap();
+            assert_eq!(data.bits().get_field::<u128>(offset, width), Ok(value));
+            data.bits_mut().set_le_field(offset, width, value).unwrap();
+            assert_eq!(data.bits().get_le_field::<u128>(offset, width), Ok(value));
+        }
+    }
+
+    // bits out of the field keep unchanged
+    let mut data = [0xff_u8; 3];
+    data.bits_mut().set_field(5, 9, 0_u16).unwrap();
+    assert_eq!(data, [0b1111_1000, 0b0000_0011, 0xff]);
+    let mut data = [0xff_u8; 3];
+    data.bits_mut().set_le_field(5, 9, 0_u16).unwrap();
+    assert_eq!(data, [0b0001_1111, 0b1100_0000, 0xff]);
+}
+
+#[test]
+fn test_bits_field_error() {
+    let mut data = [0_u8; 4];
+    assert_eq!(
+        data.bits().get_field::<u64>(30, 3),
+        Err(Error::OutOfBounds {
+            offset: 30,
+            len: 3,
+            bits: 32
+        })
+    );
+    assert_eq!(
+        data.bits().get_field::<u64>(0, 0),
+        Err(Error::InvalidWidth { width: 0, max: 128 })
+    );
+    assert_eq!(
+        data.bits_mut().set_field(0, 4, 16_u8),
+        Err(Error::ValueOverflow {
+            value: 16,
+            width: 4
+        })
+    );
+    assert_eq!(
+        [0xff_u8; 4].bits().get_field::<u8>(0, 9),
+        Err(Error::ValueOverflow {
+            value: 0x1ff,
+            width: 8
+        })
+    );
+    assert_eq!(data, [0; 4]);
+}
+
+/// Reference copy by walking every bit
+fn naive_copy(dst: &mut [u8], dst_offset: usize, src: &[u8], src_offset: usize, len: usize) {
+    let bits: Vec<bool> = src.bits().iter().skip(src_offset).take(len).collect();
+    for (i, bit) in bits.into_iter().enumerate() {
+        let (n, m) = ((dst_offset + i) / 8, 7 - (dst_offset + i) % 8);
+        dst[n] = (dst[n] & !(1 << m)) | ((bit as u8) << m);
+    }
+}
+
+#[test]
+fn test_bits_copy() {
+    let src: Vec<u8> = (0..40_u8).map(|v| v.wrapping_mul(37) ^ 0x5a).collect();
+    for (src_offset, dst_offset, len) in [
+        (0, 0, 320),
+        (0, 3, 200),
+        (5, 0, 250),
+        (7, 13, 129),
+        (9, 9, 64),
+        (0, 1, 0),
+        (1, 8, 311),
+        (8, 1, 311),
+    ] {
+        let mut a = [0xc3_u8; 40];
+        let mut b = a;
+        a.bits_mut()
+            .copy_bits_from(dst_offset, src.bits(), src_offset, len)
+            .unwrap();
+        naive_copy(&mut b, dst_offset, &src, src_offset, len);
+        assert_eq!(a, b);
+    }
+
+    for (src_offset, dst_offset, len) in [
+        (0, 3, 300),
+        (3, 0, 300),
+        (10, 75, 200),
+        (75, 10, 200),
+        (5, 5, 100),
+        (1, 8, 311),
+        (8, 1, 311),
+        (0, 64, 256),
+    ] {
+        let mut a = src.clone();
+        let mut b = src.clone();
+        a.bits_mut()
+            .copy_within_bits(dst_offset, src_offset, len)
+            .unwrap();
+        naive_copy(&mut b, dst_offset, &src, src_offset, len);
+        assert_eq!(a, b);
+    }
+
+    let mut data = [0_u8; 4];
+    assert_eq!(
+        data.bits_mut().copy_bits_from(20, [0xff; 2].bits(), 0, 16),
+        Err(Error::OutOfBounds {
+            offset: 20,
+            len: 16,
+            bits: 32
+        })
+    );
+    assert_eq!(
+        data.bits_mut().copy_within_bits(0, 30, 4),
+        Err(Error::OutOfBounds {
+            offset: 30,
+            len: 4,
+            bits: 32
+        })
+    );
+}
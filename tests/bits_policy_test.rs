@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use nbits::core::{Bitwise, LengthPolicy};
+use nbits::{Error, XBits};
+
+#[test]
+fn test_length_policy() {
+    use LengthPolicy::*;
+
+    // shorter operand is zero padded for all of `and`, `or`, `xor`
+    let mut data = [0b0011_0011, 0b0011_0011];
+    assert_eq!(data.try_bit_be_and(&[0b1111_1111], Truncate), Ok(false));
+    assert_eq!(data, [0b0000_0000, 0b0011_0011]);
+    let mut data = [0b0011_0011, 0b0011_0011];
+    assert_eq!(data.try_bit_be_or(&[0b1111_1111], ZeroExtend), Ok(false));
+    assert_eq!(data, [0b0011_0011, 0b1111_1111]);
+    let mut data = [0b0011_0011, 0b0011_0011];
+    assert_eq!(data.try_bit_le_xor(&[0b1111_1111], ZeroExtend), Ok(false));
+    assert_eq!(data, [0b1100_1100, 0b0011_0011]);
+
+    // strict length
+    let mut data = [0b0011_0011, 0b0011_0011];
+    assert_eq!(
+        data.try_bit_be_xor(&[0b1111_1111], Strict),
+        Err(Error::LengthMismatch {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        data.try_bit_le_or(&[0, 0, 0], Strict),
+        Err(Error::LengthMismatch {
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(data.try_bit_be_xor(&[0xff, 0xff], Strict), Ok(false));
+    assert_eq!(data, [0b1100_1100, 0b1100_1100]);
+
+    // longer operand
+    let mut data = [0b0011_0011, 0b0011_0011];
+    assert_eq!(
+        data.try_bit_be_or(&[0b1000_0000, 0, 0b1111_1111], ZeroExtend),
+        Err(Error::Truncated { len: 3, max: 2 })
+    );
+    assert_eq!(data, [0b0011_0011, 0b0011_0011]);
+    assert_eq!(data.try_bit_le_and(&[0xff, 0xff, 0xff], Truncate), Ok(true));
+    assert_eq!(data, [0b0011_0011, 0b0011_0011]);
+    assert_eq!(
+        data.try_bit_le_and(&[0xff, 0x0f, 0x00], ZeroExtend),
+        Ok(false)
+    );
+    assert_eq!(data, [0b0011_0011, 0b0000_0011]);
+
+    let mut data = [0_u8; 2];
+    assert_eq!(
+        data.bits_mut().try_xor([1, 0, 1].bits(), Truncate),
+        Ok(true)
+    );
+    assert_eq!(data, [0, 1]);
+    assert_eq!(
+        data.bits_mut().try_and([0, 0, 1].bits(), ZeroExtend),
+        Ok(false)
+    );
+    assert_eq!(data, [0, 1]);
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use nbits::XBits;
+
+/// Reference search by walking every bit
+fn naive_next(data: &[u8], from: usize, bit: bool) -> Option<usize> {
+    data.bits()
+        .iter()
+        .enumerate()
+        .skip(from)
+        .find(|&(_, b)| b == bit)
+        .map(|(i, _)| i)
+}
+
+fn naive_prev(data: &[u8], from: usize, bit: bool) -> Option<usize> {
+    data.bits()
+        .iter()
+        .enumerate()
+        .take(from.saturating_add(1))
+        .filter(|&(_, b)| b == bit)
+        .last()
+        .map(|(i, _)| i)
+}
+
+#[test]
+fn test_bits_search() {
+    let mut data = [0_u8; 37];
+    data[3] = 0b0001_0000;
+    data[20] = 0b1000_0001;
+    data[36] = 0b0000_0100;
+    for d in [data, data.map(|v| !v)] {
+        let bits = d.bits();
+        for from in 0..d.len() * 8 + 2 {
+            assert_eq!(bits.next_one(from), naive_next(&d, from, true));
+            assert_eq!(bits.next_zero(from), naive_next(&d, from, false));
+            assert_eq!(bits.prev_one(from), naive_prev(&d, from, true));
+            assert_eq!(bits.prev_zero(from), naive_prev(&d, from, false));
+        }
+        assert_eq!(bits.first_one(), naive_next(&d, 0, true));
+        assert_eq!(bits.last_one(), naive_prev(&d, usize::MAX, true));
+        assert_eq!(bits.first_zero(), naive_next(&d, 0, false));
+        assert_eq!(bits.last_zero(), naive_prev(&d, usize::MAX, false));
+    }
+}
+
+#[test]
+fn test_bits_ones() {
+    assert_eq!([0b1111_1111, 0b1100_0000].bits().leading_ones(), 10);
+    assert_eq!([0b1111_1111, 0b1100_0000].bits().trailing_ones(), 0);
+    assert_eq!([0b0000_0001, 0b1111_1111].bits().trailing_ones(), 9);
+    assert_eq!([0_u8; 0].bits().leading_ones(), 0);
+    assert_eq!([0_u8; 0].bits().trailing_ones(), 0);
+
+    assert_eq!([0b0010_0000, 0b0000_0100].bits().first_one(), Some(2));
+    assert_eq!([0b0010_0000, 0b0000_0100].bits().last_one(), Some(13));
+    assert_eq!([0b1101_1111, 0b1111_1011].bits().first_zero(), Some(2));
+    assert_eq!([0b1101_1111, 0b1111_1011].bits().last_zero(), Some(13));
+    assert_eq!([0_u8; 4].bits().first_one(), None);
+    assert_eq!([0xff_u8; 4].bits().last_zero(), None);
+}
+
+#[test]
+fn test_bits_positions() {
+    let mut data = vec![0_u8; 1 << 10];
+    data[0] = 0b1000_0000;
+    data[100] = 0b0010_0100;
+    data[1023] = 0b0000_0001;
+    let ones = data.bits().iter_ones();
+    assert_eq!(ones.len(), 4);
+    assert_eq!(ones.collect::<Vec<_>>(), [0, 802, 805, 8191]);
+    assert_eq!(
+        data.bits().iter_ones().rev().collect::<Vec<_>>(),
+        [8191, 805, 802, 0]
+    );
+
+    let mut ones = data.bits().iter_ones();
+    assert_eq!(ones.next(), Some(0));
+    assert_eq!(ones.next_back(), Some(8191));
+    assert_eq!(ones.len(), 2);
+    assert_eq!(ones.next_back(), Some(805));
+    assert_eq!(ones.next(), Some(802));
+    assert_eq!(ones.next(), None);
+    assert_eq!(ones.next_back(), None);
+
+    let zeros = data.bits().iter_zeros();
+    assert_eq!(zeros.len(), data.len() * 8 - 4);
+    assert!(zeros.eq((0..data.len() * 8).filter(|&i| !data.bits()[i])));
+    assert_eq!(
+        [0xff_u8; 3].bits().iter_zeros().collect::<Vec<_>>(),
+        Vec::<usize>::new()
+    );
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+#![cfg(feature = "alloc")]
+
+use nbits::core::{BitIterator, CollectBits, FromBits, TrailingBits};
+use nbits::{BitVec, Error};
+
+#[test]
+fn test_bitvec() {
+    let data: Vec<u8> = (0..40_u8).map(|v| v.wrapping_mul(151)).collect();
+    let bits: Vec<bool> = data.bit_iter().collect();
+    for len in [0, 1, 7, 8, 9, 63, 64, 65, 319, 320] {
+        let collected: BitVec = bits[..len].iter().copied().collect();
+        assert_eq!(collected.len(), len);
+        assert_eq!(collected.iter().collect::<Vec<_>>(), bits[..len]);
+        assert_eq!(collected.iter().rev().count(), len);
+        assert_eq!(
+            collected.as_bytes(),
+            Vec::from_bits(bits[..len].iter().copied())
+        );
+        assert_eq!(collected, BitVec::from_bits(bits[..len].iter().copied()));
+
+        let mut truncated = BitVec::from(data.clone());
+        truncated.truncate(len);
+        assert_eq!(truncated, collected);
+
+        let mut extended = BitVec::with_capacity(len);
+        extended.extend(bits[..len / 2].iter().copied());
+        extended.extend(bits[len / 2..len].iter().copied());
+        assert_eq!(extended, collected);
+    }
+
+    let mut bits = BitVec::from(vec![0b1010_0001]);
+    assert_eq!(bits.get(0), Some(true));
+    assert_eq!(bits.get(8), None);
+    assert_eq!(bits.pop(), Some(true));
+    assert_eq!(bits.pop(), Some(false));
+    assert_eq!(bits.as_bytes(), [0b1010_0000]);
+    bits.truncate(1);
+    assert_eq!((bits.len(), bits.as_bytes()), (1, &[0b1000_0000][..]));
+    assert_eq!(bits.pop(), Some(true));
+    assert_eq!(bits.pop(), None);
+    assert!(bits.is_empty());
+    assert!(bits.into_bytes().is_empty());
+}
+
+#[test]
+fn test_collect_bits() {
+    let indices = [1_u16, 2047, 1024, 0, 5];
+    let data: Vec<u8> = indices.iter().copied().collect_bits(11);
+    assert_eq!(data, Vec::from_bits_chunk(indices.iter().copied(), 11));
+
+    let bits: BitVec = indices.iter().copied().collect_bits(11);
+    assert_eq!(bits.len(), 55);
+    assert_eq!(bits.as_bytes(), data);
+    let chunks = bits.bits().chunks::<u16>(11).take(bits.len() / 11);
+    assert_eq!(chunks.collect::<Vec<_>>(), indices);
+
+    let boxed: Box<[u8]> = indices.iter().copied().collect_bits(11);
+    assert_eq!(*boxed, *data);
+
+    assert_eq!(
+        indices.iter().copied().collect_chunks::<7>(11),
+        Ok(data.clone().try_into().unwrap())
+    );
+    assert_eq!(
+        indices.iter().copied().collect_chunks::<8>(11),
+        Err(Error::LengthMismatch {
+            expected: 8,
+            found: 7
+        })
+    );
+
+    let strict: Result<BitVec, _> = [1_u16, 2048].into_iter().try_collect_bits(11);
+    assert_eq!(
+        strict,
+        Err(Error::ChunkOverflow {
+            index: 1,
+            value: 2048,
+            width: 11
+        })
+    );
+
+    // base64 "/w==", the trailing 4 bits are dropped
+    let indices = [0b11_1111_u8, 0b11_0000];
+    let kept = BitVec::from_bits_chunk_with(indices.into_iter(), 6, TrailingBits::Keep);
+    assert_eq!(kept.map(|bits| bits.len()), Ok(12));
+    let dropped = BitVec::from_bits_chunk_with(indices.into_iter(), 6, TrailingBits::RequireZero);
+    assert_eq!(dropped, Ok(BitVec::from(vec![0xff])));
+}
@@ -1,4 +1,5 @@
 #![cfg(test)]
+#![cfg(feature = "alloc")]
 use hex::FromHex;
 use nbits::core::{BitIterator, FromBits};
 
@@ -136,3 +137,253 @@ fn test_conjoin_debug() {
         vec![0b1111_1111, 0b1111_1111]
     );
 }
+
+#[test]
+fn test_chunks_wide() {
+    // 40 bits timestamps, 57 bits ids
+    let data: Vec<u8> = (0..=255_u8).collect();
+    for n in [33, 40, 48, 57, 64] {
+        let chunks: Vec<u64> = data.bit_chunks(n).collect();
+        assert_eq!(chunks.len(), (data.len() * 8).div_ceil(n));
+        let bytes = Vec::from_bits_chunk(chunks.into_iter(), n);
+        assert_eq!(bytes[..data.len()], data);
+        assert!(bytes[data.len()..].iter().all(|&v| v == 0));
+    }
+    for n in [65, 100, 127, 128] {
+        let chunks: Vec<u128> = data.bit_chunks(n).collect();
+        assert_eq!(chunks.len(), (data.len() * 8).div_ceil(n));
+        let bytes = Vec::from_bits_chunk(chunks.into_iter(), n);
+        assert_eq!(bytes[..data.len()], data);
+    }
+
+    assert_eq!(
+        [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]
+            .bit_chunks(40)
+            .collect::<Vec<u64>>(),
+        vec![0x12_3456_789a, 0xbc_0000_0000]
+    );
+    assert_eq!(
+        Vec::from_bits_chunk([0x12_3456_789a_u64, 0xbc].into_iter(), 40),
+        vec![0x12, 0x34, 0x56, 0x78, 0x9a, 0x00, 0x00, 0x00, 0x00, 0xbc]
+    );
+    assert_eq!(
+        Vec::from_bits_chunk([u128::MAX, 0].into_iter(), 128),
+        [[0xff; 16], [0; 16]].concat()
+    );
+}
+
+#[test]
+fn test_chunks_strict() {
+    use nbits::Error;
+
+    // mnemonic indices out of range
+    let indices = [106_u16, 1280, 2048, 31];
+    assert_eq!(
+        Vec::try_from_bits_chunk(indices.into_iter(), 11),
+        Err(Error::ChunkOverflow {
+            index: 2,
+            value: 2048,
+            width: 11
+        })
+    );
+    assert_eq!(
+        Vec::try_from_bits_chunk(INDICES_LIST[0].iter().copied(), 11),
+        Ok(Vec::from_bits_chunk(INDICES_LIST[0].iter().copied(), 11))
+    );
+
+    let data = Vec::from_hex(ENTROPY_LIST[0]).expect("entropy");
+    let indices: Result<Vec<u16>, _> = data.try_bit_chunks(11).collect();
+    assert_eq!(indices.as_deref(), Ok(INDICES_LIST[0]));
+    let indices: Result<Vec<u8>, _> = data.try_bit_chunks(11).collect();
+    assert_eq!(
+        indices,
+        Err(Error::ChunkOverflow {
+            index: 1,
+            value: 1280,
+            width: 8
+        })
+    );
+    assert_eq!(
+        [0xff_u8, 0x7f].try_bit_chunks::<i8>(8).collect::<Vec<_>>(),
+        vec![
+            Err(Error::ChunkOverflow {
+                index: 0,
+                value: 0xff,
+                width: 7
+            }),
+            Ok(0x7f)
+        ]
+    );
+    assert_eq!(
+        [0x80_u8, 0].try_bit_chunks::<i16>(16).collect::<Vec<_>>(),
+        vec![Err(Error::ChunkOverflow {
+            index: 0,
+            value: 0x8000,
+            width: 15
+        })]
+    );
+    assert_eq!(
+        [0xff_u8; 16].try_bit_chunks::<i128>(128).next(),
+        Some(Err(Error::ChunkOverflow {
+            index: 0,
+            value: u128::MAX,
+            width: 127
+        }))
+    );
+}
+
+#[test]
+fn test_chunks_iterator() {
+    let data: Vec<u8> = (0..100_u8).map(|v| v.wrapping_mul(151)).collect();
+    for n in [1, 3, 6, 8, 11, 32, 57, 64, 100, 128] {
+        let forward: Vec<u128> = data.bit_chunks(n).collect();
+        let mut backward: Vec<u128> = data.bit_chunks(n).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        assert_eq!(data.bit_chunks::<u128>(n).len(), forward.len());
+
+        // mixed front and back
+        let mut chunks = data.bit_chunks::<u128>(n);
+        let (mut head, mut tail) = (vec![], vec![]);
+        while let Some(v) = chunks.next() {
+            head.push(v);
+            tail.extend(chunks.next_back());
+            assert_eq!(chunks.len(), forward.len() - head.len() - tail.len());
+        }
+        tail.reverse();
+        assert_eq!([head, tail].concat(), forward);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next_back(), None);
+
+        // skip forward
+        for k in [0, 1, 5, forward.len() / 2, forward.len()] {
+            let mut chunks = data.bit_chunks::<u128>(n);
+            assert_eq!(chunks.nth(k), forward.get(k).copied());
+            assert_eq!(chunks.next(), forward.get(k + 1).copied());
+        }
+    }
+    assert_eq!([0_u8; 0].bit_chunks::<u8>(3).len(), 0);
+}
+
+#[test]
+fn test_chunks_padding() {
+    use nbits::core::{ChunkPadding, TrailingBits};
+    use nbits::Error;
+
+    let data = [0b1111_1111; 4];
+    let chunks = |padding| {
+        data.bit_chunks_with::<u16>(11, padding)
+            .map(|vs| (vs.clone().collect::<Vec<_>>(), vs.rev().collect::<Vec<_>>()))
+    };
+    let (forward, mut backward) = chunks(ChunkPadding::OnePad).unwrap();
+    backward.reverse();
+    assert_eq!(forward, [0x7ff; 3]);
+    assert_eq!(forward, backward);
+    let (forward, mut backward) = chunks(ChunkPadding::ZeroPad).unwrap();
+    backward.reverse();
+    assert_eq!(forward, [0x7ff, 0x7ff, 0x7fe]);
+    assert_eq!(forward, backward);
+    let (forward, backward) = chunks(ChunkPadding::Drop).unwrap();
+    assert_eq!(forward, [0x7ff; 2]);
+    assert_eq!(backward, [0x7ff; 2]);
+    assert_eq!(
+        chunks(ChunkPadding::Error),
+        Err(Error::PartialChunk {
+            bits: 10,
+            width: 11
+        })
+    );
+    assert!(data.bit_chunks_with::<u8>(8, ChunkPadding::Error).is_ok());
+
+    // base64 "QUI=" decodes to "AB"
+    let indices = [16_u8, 20, 8];
+    assert_eq!(
+        Vec::from_bits_chunk_with(indices.into_iter(), 6, TrailingBits::RequireZero),
+        Ok(b"AB".to_vec())
+    );
+    assert_eq!(
+        Vec::from_bits_chunk_with([16_u8, 20, 9].into_iter(), 6, TrailingBits::RequireZero),
+        Err(Error::NonZeroTrailing { bits: 2 })
+    );
+    assert_eq!(
+        Vec::from_bits_chunk_with([16_u8, 20, 9].into_iter(), 6, TrailingBits::Drop),
+        Ok(b"AB".to_vec())
+    );
+    assert_eq!(
+        Vec::from_bits_chunk_with([16_u8, 20, 9].into_iter(), 6, TrailingBits::Keep),
+        Ok(vec![b'A', b'B', 0b0100_0000])
+    );
+}
+
+#[test]
+fn test_conjoin_targets() {
+    use nbits::core::BitFill;
+    use nbits::{Error, NBits, TryFromBits};
+
+    let data: Vec<u8> = (0..32_u8).map(|v| v.wrapping_mul(151)).collect();
+    for n in [1, 5, 6, 8, 11, 64, 127, 128] {
+        let chunks: Vec<u128> = data.bit_chunks(n).collect();
+        let expected = Vec::from_bits_chunk(chunks.iter().copied(), n);
+        let boxed = Box::<[u8]>::from_bits_chunk(chunks.iter().copied(), n);
+        assert_eq!(*boxed, *expected);
+
+        let mut buf = [0xa5_u8; 64];
+        let bits = buf.bit_fill_chunk(chunks.iter().copied(), n);
+        assert_eq!(bits, Ok(chunks.len() * n));
+        assert_eq!(buf[..expected.len()], *expected);
+        assert!(buf[expected.len()..].iter().all(|&v| v == 0xa5));
+
+        let array = <[u8; 32]>::try_from_bits_chunk(chunks.iter().copied(), n);
+        match expected.len() {
+            32 => assert_eq!(array.as_ref().map(|v| &v[..]), Ok(&expected[..])),
+            found => assert_eq!(
+                array,
+                Err(Error::LengthMismatch {
+                    expected: 32,
+                    found
+                })
+            ),
+        }
+    }
+
+    let bits: Vec<bool> = data.bit_iter().collect();
+    assert_eq!(
+        <[u8; 32]>::try_from_bits(bits.iter().copied()).map(Vec::from),
+        Ok(data.clone())
+    );
+    assert_eq!(
+        NBits::<32>::try_from_bits(bits.iter().copied()),
+        Ok(NBits(data.clone().try_into().unwrap()))
+    );
+    assert_eq!(
+        Box::<[u8]>::from_bits(bits.iter().copied()),
+        data.clone().into()
+    );
+    assert_eq!(
+        NBits::<31>::try_from_bits(bits.iter().copied()),
+        Err(Error::LengthMismatch {
+            expected: 31,
+            found: 32
+        })
+    );
+    assert_eq!(
+        <[u8; 2]>::try_from_bits_chunk([0b1111_u8, 0b1_0000].into_iter(), 4),
+        Err(Error::ChunkOverflow {
+            index: 1,
+            value: 0b1_0000,
+            width: 4
+        })
+    );
+
+    let mut buf = [0_u8; 31];
+    assert_eq!(
+        buf.bit_fill(bits.iter().copied()),
+        Err(Error::OutOfBounds {
+            offset: 248,
+            len: 1,
+            bits: 248
+        })
+    );
+    assert_eq!(buf[..], data[..31]);
+    assert_eq!([0_u8; 0].bit_fill([].into_iter()), Ok(0));
+}
@@ -0,0 +1,164 @@
+#![cfg(test)]
+#![cfg(feature = "std")]
+
+use nbits::codes::{CodeRead, CodeWrite};
+use nbits::core::{BitCursor, BitCursorMut};
+use nbits::io::{BitReader, BitWriter};
+use nbits::Error;
+use std::io::{Cursor, ErrorKind};
+
+fn samples() -> Vec<u64> {
+    let mut values: Vec<u64> = (1..300).collect();
+    values.extend((1..64).flat_map(|n| [(1 << n) - 1, 1 << n, (1 << n) + 1]));
+    values.extend([u64::MAX - 1, u64::MAX]);
+    values
+}
+
+#[test]
+fn test_codes_roundtrip() {
+    let values = samples();
+    let mut data = vec![0_u8; 1 << 16];
+    let mut cursor = BitCursorMut::new(&mut data);
+    for &v in &values {
+        cursor.write_exp_golomb(v).unwrap();
+        cursor.write_signed_exp_golomb(v as i64).unwrap();
+        cursor
+            .write_signed_exp_golomb((v as i64).wrapping_neg())
+            .unwrap();
+        cursor.write_elias_gamma(v).unwrap();
+        cursor.write_elias_delta(v).unwrap();
+        cursor.write_elias_omega(v).unwrap();
+        cursor.write_rice(v, 60).unwrap();
+        cursor.write_golomb(v, 1 << 60 | 3).unwrap();
+    }
+    let written = cursor.position();
+
+    let mut cursor = BitCursor::new(&data);
+    for &v in &values {
+        assert_eq!(cursor.read_exp_golomb(), Ok(v));
+        assert_eq!(cursor.read_signed_exp_golomb(), Ok(v as i64));
+        assert_eq!(
+            cursor.read_signed_exp_golomb(),
+            Ok((v as i64).wrapping_neg())
+        );
+        assert_eq!(cursor.read_elias_gamma(), Ok(v));
+        assert_eq!(cursor.read_elias_delta(), Ok(v));
+        assert_eq!(cursor.read_elias_omega(), Ok(v));
+        assert_eq!(cursor.read_rice(60), Ok(v));
+        assert_eq!(cursor.read_golomb(1 << 60 | 3), Ok(v));
+    }
+    assert_eq!(cursor.position(), written);
+}
+
+#[test]
+fn test_codes_small_parameters() {
+    for param in [1_u64, 2, 3, 5, 7, 8, 10] {
+        let mut writer = BitWriter::new(vec![]);
+        for v in 0..200 {
+            writer.write_rice(v, param as usize).unwrap();
+            writer.write_golomb(v, param).unwrap();
+        }
+        writer.pad_to_byte(false).unwrap();
+
+        let mut reader = BitReader::new(Cursor::new(writer.into_inner()));
+        for v in 0..200 {
+            assert_eq!(reader.read_rice(param as usize).unwrap(), v);
+            assert_eq!(reader.read_golomb(param).unwrap(), v);
+        }
+    }
+
+    // Golomb code with a power of two divisor is Rice code
+    for m in [1_u64, 2, 4, 16] {
+        let (mut rice, mut golomb) = ([0_u8; 1024], [0_u8; 1024]);
+        let (mut r, mut g) = (BitCursorMut::new(&mut rice), BitCursorMut::new(&mut golomb));
+        for v in 0..100 {
+            r.write_rice(v, m.trailing_zeros() as usize).unwrap();
+            g.write_golomb(v, m).unwrap();
+        }
+        assert_eq!(rice, golomb);
+    }
+}
+
+#[test]
+fn test_codes_stream() {
+    let mut writer = BitWriter::new(vec![]);
+    writer.write_exp_golomb(u64::MAX).unwrap();
+    writer.write_signed_exp_golomb(i64::MIN).unwrap();
+    writer.write_elias_omega(u64::MAX).unwrap();
+    writer.write_bits(0b101, 3).unwrap();
+    let err = writer.write_elias_delta(0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    writer.pad_to_byte(false).unwrap();
+    let data = writer.into_inner();
+
+    // 64 zeros, a one and 64 bits, then 64 zeros, a one and 64 bits
+    assert_eq!(data[..8], [0; 8]);
+    let mut cursor = BitCursor::new(&data);
+    assert_eq!(cursor.read_exp_golomb(), Ok(u64::MAX));
+    assert_eq!(cursor.position(), 129);
+
+    let mut reader = BitReader::new(Cursor::new(&data));
+    assert_eq!(reader.read_exp_golomb().unwrap(), u64::MAX);
+    assert_eq!(reader.read_signed_exp_golomb().unwrap(), i64::MIN);
+    assert_eq!(reader.read_elias_omega().unwrap(), u64::MAX);
+    assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+}
+
+#[test]
+fn test_codes_invalid() {
+    // values without a code
+    let mut data = [0_u8; 4];
+    let mut cursor = BitCursorMut::new(&mut data);
+    let unrepresentable = Err(Error::Unrepresentable { value: 0 });
+    assert_eq!(cursor.write_elias_gamma(0), unrepresentable);
+    assert_eq!(cursor.write_elias_delta(0), unrepresentable);
+    assert_eq!(cursor.write_elias_omega(0), unrepresentable);
+    assert!(cursor.write_exp_golomb(1 << 20).is_err());
+
+    // codes of values overflow u64
+    let mut data = [0_u8; 32];
+    data[8] = 0b1000_0000;
+    assert_eq!(BitCursor::new(&data).read_exp_golomb(), Ok(u64::MAX));
+    assert_eq!(
+        BitCursor::new(&data).read_elias_gamma(),
+        Err(Error::InvalidCode)
+    );
+    assert_eq!(
+        BitCursor::new(&data).read_signed_exp_golomb(),
+        Err(Error::InvalidCode)
+    );
+    data[9] = 0b1000_0000;
+    assert_eq!(
+        BitCursor::new(&data).read_exp_golomb(),
+        Err(Error::InvalidCode)
+    );
+    data[8] = 0b0100_0000;
+    assert_eq!(
+        BitCursor::new(&data).read_exp_golomb(),
+        Err(Error::InvalidCode)
+    );
+    assert_eq!(
+        BitCursor::new(&[0xff; 16]).read_elias_omega(),
+        Err(Error::InvalidCode)
+    );
+    assert_eq!(
+        BitCursor::new(&[0b0000_0010, 0b0000_1000]).read_elias_delta(),
+        Err(Error::InvalidCode)
+    );
+    assert_eq!(
+        BitCursor::new(&[0xc0, 0, 0, 0, 0, 0, 0, 0, 0]).read_rice(63),
+        Err(Error::InvalidCode)
+    );
+
+    // truncated codes
+    assert!(matches!(
+        BitCursor::new(&[0b0000_0001]).read_elias_gamma(),
+        Err(Error::OutOfBounds { .. })
+    ));
+    let mut reader = BitReader::new(Cursor::new([0b1111_1111]));
+    let err = reader.read_golomb(3).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    let mut reader = BitReader::new(Cursor::new([0; 16]));
+    let err = reader.read_elias_gamma().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,224 @@
+#![cfg(test)]
+#![cfg(feature = "std")]
+
+use nbits::io::BitReader;
+use nbits::{Error, XBits};
+use std::collections::VecDeque;
+use std::io::{self, Cursor, ErrorKind, Write};
+
+#[test]
+fn test_bit_reader() {
+    let data: Vec<u8> = (0..64_u8).map(|v| v.wrapping_mul(73) ^ 0xa5).collect();
+    for n in [1, 3, 7, 8, 11, 31, 57, 64] {
+        let mut reader = BitReader::new(Cursor::new(&data));
+        let count = data.len() * 8 / n;
+        for i in 0..count {
+            assert_eq!(reader.position(), (i * n) as u64);
+            let value = reader.read_bits(n).unwrap();
+            assert_eq!(Ok(value as u128), data.bits().get_field::<u128>(i * n, n));
+        }
+        assert_eq!(reader.is_aligned(), (count * n).is_multiple_of(8));
+        let err = reader.read_bits(n).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    let mut reader = BitReader::new(Cursor::new([0b1011_0000, 0xff, 0x80, 0x01]));
+    assert!(reader.read_bit().unwrap());
+    assert_eq!(reader.read_signed(3).unwrap(), 0b011);
+    assert_eq!(reader.align_to_byte(), 4);
+    assert_eq!(reader.align_to_byte(), 0);
+    assert_eq!(reader.read_signed(8).unwrap(), -1);
+    assert_eq!(
+        reader.read_signed(16).unwrap(),
+        i16::from_be_bytes([0x80, 0x01]) as i64
+    );
+    assert_eq!(reader.position(), 32);
+    assert_eq!(reader.read_bits(0).unwrap(), 0);
+    assert_eq!(
+        reader.read_bit().unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_bit_reader_bytes() {
+    let mut reader = BitReader::new(Cursor::new([0x12, 0x34, 0x56, 0x78]));
+    let mut buf = [0; 2];
+    reader.read_bytes(&mut buf).unwrap();
+    assert_eq!(buf, [0x12, 0x34]);
+    assert_eq!(reader.read_bits(4).unwrap(), 0x5);
+    reader.read_bytes(&mut buf[..1]).unwrap();
+    assert_eq!(buf, [0x67, 0x34]);
+    assert_eq!(reader.position(), 28);
+    let err = reader.read_bytes(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_bit_reader_partial_eof() {
+    // a value cut by the end of stream is read again once more data arrives
+    let mut reader = BitReader::new(VecDeque::from([0xab, 0xcd]));
+    assert_eq!(reader.read_bits(4).unwrap(), 0xa);
+    let err = reader.read_bits(24).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(reader.position(), 4);
+    assert!(!reader.is_aligned());
+
+    reader.get_mut().extend([0xef, 0x12]);
+    assert!(reader.read_bits(64).is_err());
+    assert_eq!(reader.read_bits(24).unwrap(), 0xb_cdef1);
+    assert_eq!(reader.position(), 28);
+    assert_eq!(reader.read_bits(4).unwrap(), 0x2);
+
+    // bytes kept by a failed read come before the stream
+    let mut reader = BitReader::new(VecDeque::from([0x12, 0x34]));
+    assert!(reader.read_bits(24).is_err());
+    reader.get_mut().extend([0x56, 0x78]);
+    let mut buf = [0; 4];
+    reader.read_bytes(&mut buf).unwrap();
+    assert_eq!(buf, [0x12, 0x34, 0x56, 0x78]);
+
+    // bytes read before the end of stream are counted
+    let mut reader = BitReader::new(VecDeque::from([0x12, 0x34]));
+    let err = reader.read_bytes(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(buf[..2], [0x12, 0x34]);
+    assert_eq!(reader.position(), 16);
+}
+
+#[test]
+fn test_bit_writer() {
+    use nbits::io::BitWriter;
+
+    let values: Vec<(u64, usize)> = (0..200_u64)
+        .map(|i| {
+            let n = (i as usize * 7) % 64 + 1;
+            (i.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> (64 - n), n)
+        })
+        .collect();
+    let mut writer = BitWriter::new(vec![]);
+    for &(value, n) in &values {
+        writer.write_bits(value, n).unwrap();
+    }
+    let bits = values.iter().map(|&(_, n)| n as u64).sum::<u64>();
+    assert_eq!(writer.position(), bits);
+    writer.pad_to_byte(false).unwrap();
+    writer.flush().unwrap();
+    let data = writer.into_inner();
+    assert_eq!(data.len() as u64, bits.div_ceil(8));
+
+    let mut reader = BitReader::new(Cursor::new(&data));
+    for &(value, n) in &values {
+        assert_eq!(reader.read_bits(n).unwrap(), value);
+    }
+
+    // signed values and bytes
+    let mut writer = BitWriter::new(vec![]);
+    writer.write_signed(-1, 3).unwrap();
+    writer.write_signed(i64::MIN, 64).unwrap();
+    writer.write_bytes(&[0x12, 0x34]).unwrap();
+    writer.write_bit(true).unwrap();
+    assert_eq!(writer.pad_to_byte(true).unwrap(), 4);
+    writer.write_bytes(&[0x56]).unwrap();
+    let data = writer.into_inner();
+    let mut reader = BitReader::new(Cursor::new(&data));
+    assert_eq!(reader.read_signed(3).unwrap(), -1);
+    assert_eq!(reader.read_signed(64).unwrap(), i64::MIN);
+    let mut buf = [0; 2];
+    reader.read_bytes(&mut buf).unwrap();
+    assert_eq!(buf, [0x12, 0x34]);
+    assert_eq!(reader.read_bits(5).unwrap(), 0b1_1111);
+    assert_eq!(reader.read_bits(8).unwrap(), 0x56);
+
+    let mut writer = BitWriter::new(vec![]);
+    let err = writer.write_bits(16, 4).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let err = writer.write_signed(-9, 4).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(
+        err.into_inner().unwrap().downcast_ref::<Error>(),
+        Some(&Error::SignedOverflow {
+            value: -9,
+            width: 4
+        })
+    );
+    let err = writer.write_signed(8, 4).unwrap_err();
+    assert_eq!(
+        err.get_ref().unwrap().to_string(),
+        "[nbits] signed value `8` overflow `4` bits"
+    );
+    assert_eq!(writer.position(), 0);
+}
+
+/// Accepts one byte at a time while `limit` allows, then fails
+struct LimitedWriter {
+    data: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match (buf.first(), self.limit) {
+            (Some(_), 0) => Err(io::Error::other("limit reached")),
+            (Some(&byte), _) => {
+                self.data.push(byte);
+                self.limit -= 1;
+                Ok(1)
+            }
+            (None, _) => Ok(0),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_bit_writer_partial_write() {
+    use nbits::io::BitWriter;
+
+    // bytes accepted before the error are counted, the rest of the value is resumed
+    let limited = LimitedWriter {
+        data: vec![],
+        limit: 1,
+    };
+    let mut writer = BitWriter::new(limited);
+    writer.write_bits(0b101, 3).unwrap();
+    let err = writer.write_bits(0x1234, 16).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(writer.position(), 8);
+    assert!(writer.is_aligned());
+    assert_eq!(writer.get_ref().data, [0b1010_0010]);
+
+    writer.get_mut().limit = 2;
+    writer.write_bits(0x1234 & 0x7ff, 11).unwrap();
+    assert_eq!(writer.pad_to_byte(false).unwrap(), 5);
+    assert_eq!(writer.position(), 24);
+    assert_eq!(writer.into_inner().data, [0b1010_0010, 0x46, 0x80]);
+
+    // a failed write of a partial byte keeps the pending bits
+    let limited = LimitedWriter {
+        data: vec![],
+        limit: 0,
+    };
+    let mut writer = BitWriter::new(limited);
+    writer.write_bits(0b101, 3).unwrap();
+    assert!(writer.write_bits(0x1f, 5).is_err());
+    assert_eq!(writer.position(), 3);
+    writer.get_mut().limit = 1;
+    writer.write_bits(0x1f, 5).unwrap();
+    assert_eq!(writer.into_inner().data, [0b1011_1111]);
+
+    // aligned bytes are counted as they are accepted
+    let limited = LimitedWriter {
+        data: vec![],
+        limit: 1,
+    };
+    let mut writer = BitWriter::new(limited);
+    assert!(writer.write_bytes(&[0x12, 0x34, 0x56]).is_err());
+    assert_eq!(writer.position(), 8);
+    writer.get_mut().limit = 2;
+    writer.write_bytes(&[0x34, 0x56]).unwrap();
+    assert_eq!(writer.into_inner().data, [0x12, 0x34, 0x56]);
+}
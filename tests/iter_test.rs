@@ -1,4 +1,5 @@
 #![cfg(test)]
+#![cfg(feature = "alloc")]
 
 use nbits::core::{BitIterator, FromBits};
 
@@ -63,17 +64,17 @@ const BITS_LIST: &[&[bool]] = &[
 #[test]
 fn test_doc() {
     assert_eq!(
-        vec![0b1111_1111].bit_chunks(6).collect::<Vec<u8>>(),
+        [0b1111_1111].bit_chunks(6).collect::<Vec<u8>>(),
         vec![0b11_1111, 0b11_0000]
     );
     assert_eq!(
-        vec![0b1111_1111, 0b1111_1111]
+        [0b1111_1111, 0b1111_1111]
             .bit_chunks(6)
             .collect::<Vec<u8>>(),
         vec![0b11_1111, 0b11_1111, 0b11_1100]
     );
     assert_eq!(
-        vec![0b1111_1111; 3].bit_chunks(11).collect::<Vec<u16>>(),
+        [0b1111_1111; 3].bit_chunks(11).collect::<Vec<u16>>(),
         vec![0b111_1111_1111, 0b111_1111_1111, 0b110_0000_0000]
     );
 
@@ -83,7 +84,7 @@ fn test_doc() {
     );
     assert_eq!(
         Vec::from_bits_chunk([0b1111_u16, 0b1111, 0b1111].into_iter(), 6),
-        vec![0b001111_00, 0b1111_0011, 0b1100_0000]
+        vec![0b0011_1100, 0b1111_0011, 0b1100_0000]
     );
 
     assert_eq!(
@@ -23,6 +23,15 @@ fn test_bits_arith() {
     assert_eq!(Bits([0, 0, 1]) * &Bits([5, 255, 255]), Bits([5, 255, 255]));
     assert_eq!(Bits([1, 1, 1]) * &Bits([0, 0, 255]), Bits([255, 255, 255]));
     assert_eq!(Bits([0, 1, 255]) * &Bits([0, 0, 255]), Bits([1, 253, 1]));
+
+    assert_eq!(Bits([0, 6]) % &Bits([0, 4]), Bits([0, 2]));
+    assert_eq!(Bits([1, 0]) % &Bits([0, 24]), Bits([0, 16]));
+}
+
+#[test]
+#[should_panic]
+fn test_mul_overflow() {
+    let _ = Bits([1, 0]) * &Bits([1, 0]);
 }
 
 #[test]
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use nbits::{Error, NBits};
+
+#[test]
+fn test_nbits_primitive() {
+    assert_eq!(NBits::<1>::from(0xab_u8), NBits([0xab]));
+    assert_eq!(NBits::<4>::from(0xab_u8), NBits([0, 0, 0, 0xab]));
+    assert_eq!(NBits::<8>::from(u64::MAX), NBits([0xff; 8]));
+    assert_eq!(NBits::<17>::from(u128::MAX).0[1..], [0xff; 16]);
+    assert_eq!(NBits::<64>::from(u128::MAX).0[48..], [0xff; 16]);
+
+    let value = NBits([0, 0, 0x12, 0x34]);
+    assert_eq!(u128::try_from(value.clone()), Ok(0x1234));
+    assert_eq!(u64::try_from(value.clone()), Ok(0x1234));
+    assert_eq!(u32::try_from(value.clone()), Ok(0x1234));
+    assert_eq!(u16::try_from(value.clone()), Ok(0x1234));
+    assert_eq!(
+        u8::try_from(value),
+        Err(Error::Truncated { len: 4, max: 1 })
+    );
+    assert_eq!(u64::try_from(NBits::<0>::new()), Ok(0));
+    assert_eq!(u8::try_from(NBits([0, 0, 0xff])), Ok(0xff));
+}
+
+#[test]
+fn test_nbits_resize() {
+    let value = NBits([0, 1, 2]);
+    assert_eq!(value.resize::<3>(), Ok(value.clone()));
+    assert_eq!(value.resize::<5>(), Ok(NBits([0, 0, 0, 1, 2])));
+    assert_eq!(value.resize::<2>(), Ok(NBits([1, 2])));
+    assert_eq!(
+        value.resize::<1>(),
+        Err(Error::Truncated { len: 3, max: 1 })
+    );
+    assert_eq!(
+        value.resize::<0>(),
+        Err(Error::Truncated { len: 3, max: 0 })
+    );
+    assert_eq!(NBits([0, 0]).resize::<0>(), Ok(NBits([])));
+}
+
+#[test]
+fn test_nbits_bytes() {
+    let value = NBits::from_be_bytes([1, 2, 3]);
+    assert_eq!(value, NBits([1, 2, 3]));
+    assert_eq!(value.to_be_bytes(), [1, 2, 3]);
+    assert_eq!(value.to_le_bytes(), [3, 2, 1]);
+    assert_eq!(NBits::from_le_bytes([3, 2, 1]), value);
+    assert_eq!(
+        NBits::from_le_bytes(0x0102_0304_u32.to_le_bytes()),
+        NBits::from(0x0102_0304_u32)
+    );
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+use nbits::{nbits, NBits, UBits};
+
+const ORDER: NBits<32> =
+    nbits!(0xFFFFFFFF_FFFFFFFF_FFFFFFFF_FFFFFFFE_BAAEDCE6_AF48A03B_BFD25E8C_D0364141);
+
+#[test]
+fn test_nbits_literal() {
+    assert_eq!(ORDER.0[..4], [0xff; 4]);
+    assert_eq!(ORDER.0[28..], [0xd0, 0x36, 0x41, 0x41]);
+    assert_eq!(
+        nbits!(32; 115792089237316195423570985008687907852837564279074904382605163141518161494337),
+        ORDER
+    );
+
+    assert_eq!(nbits!(1; 0), NBits([0]));
+    assert_eq!(nbits!(2; 0x00ff), NBits([0, 0xff]));
+    assert_eq!(nbits!(2; 0b1_0000_0000), NBits([1, 0]));
+    assert_eq!(nbits!(17; 340282366920938463463374607431768211456).0[0], 1);
+    assert_eq!(nbits!(4; "4_294_967_295"), NBits([0xff; 4]));
+    assert_eq!(nbits!(0; 0), NBits([]));
+
+    let value: NBits<3> = nbits!(0xAbCdEf);
+    assert_eq!(value.to_le_bytes(), [0xef, 0xcd, 0xab]);
+}
+
+#[test]
+fn test_const_fn() {
+    const VALUE: NBits<3> = NBits::from_le_bytes([3, 2, 1]);
+    const WIDE: NBits<4> = match VALUE.resize::<4>() {
+        Ok(value) => value,
+        Err(_) => panic!(),
+    };
+    const BIT: bool = WIDE.bit(31);
+    const BYTES: [u8; 4] = WIDE.to_be_bytes();
+    const MAX: UBits<12, 2> = UBits::max();
+    const ONE: UBits<12, 2> = UBits::one();
+    assert_eq!(VALUE, NBits([1, 2, 3]));
+    const { assert!(BIT) };
+    assert_eq!(BYTES, [0, 1, 2, 3]);
+    assert_eq!(MAX.as_bytes(), [0x0f, 0xff]);
+    assert_eq!(
+        ONE.checked_add(&ONE).map(UBits::into_nbits),
+        Some(nbits!(2; 2))
+    );
+}
+
+#[test]
+#[should_panic(expected = "literal overflow")]
+fn test_literal_overflow() {
+    let _ = NBits::<1>::from_literal("256");
+}
+
+#[test]
+fn test_literal_invalid() {
+    // separators go after the first digit, not before it or right after the prefix
+    for literal in ["0x1g", "_1", "__1", "0x_1", "0b_1", "\"_1\""] {
+        let err = std::panic::catch_unwind(|| NBits::<1>::from_literal(literal)).unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(msg.contains("invalid digit"), "{literal}: {msg}");
+    }
+    assert_eq!(NBits::<1>::from_literal("1__"), NBits([1]));
+}
@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use nbits::NBits as Bits;
+
+#[test]
+fn test_bits_operands() {
+    let (a, b) = (Bits([0, 12]), Bits([0, 5]));
+    assert_eq!(a.clone() + b.clone(), Bits([0, 17]));
+    assert_eq!(a.clone() + &b, Bits([0, 17]));
+    assert_eq!(&a + b.clone(), Bits([0, 17]));
+    assert_eq!(&a + &b, Bits([0, 17]));
+    assert_eq!(&a - &b, Bits([0, 7]));
+    assert_eq!(&a * &b, Bits([0, 60]));
+    assert_eq!(&a / &b, Bits([0, 2]));
+    assert_eq!(&a % &b, Bits([0, 2]));
+    assert_eq!(&a + 1_u8, Bits([0, 13]));
+    assert_eq!(&a & &b, Bits([0, 4]));
+    assert_eq!(&a | b.clone(), Bits([0, 13]));
+    assert_eq!(a.clone() ^ b.clone(), Bits([0, 9]));
+    assert_eq!(!&a, Bits([0xff, 0xf3]));
+    assert_eq!(&a << 4, Bits([0, 0xc0]));
+    assert_eq!(&a >> 2, Bits([0, 3]));
+    assert_eq!(-&b, Bits([0xff, 0xfb]));
+    assert_eq!(-(-b.clone()), b);
+
+    let mut x = a.clone();
+    x += b.clone();
+    x -= &b;
+    x *= b.clone();
+    x /= b.clone();
+    x %= b.clone();
+    x |= b.clone();
+    x &= a.clone();
+    x ^= b.clone();
+    assert_eq!(x, Bits([0, 4 ^ 5]));
+}
+
+#[test]
+fn test_bits_primitive_cmp() {
+    let a = Bits([0, 0, 1, 0]);
+    assert!(a == 256);
+    assert!(256 == a);
+    assert!(a != 255);
+    assert!(a > 255 && a < 257);
+    assert!(255 < a && 257 > a);
+    assert!(Bits([1; 9]) > u64::MAX);
+    assert!(Bits([0; 9]) == 0);
+    assert!(Bits([1]) < 256);
+    assert!(Bits::<0>::new() == 0);
+}
+
+#[test]
+fn test_bits_sum_product() {
+    let values = [Bits([0, 3]), Bits([0, 4]), Bits([0, 5])];
+    assert_eq!(values.iter().sum::<Bits<2>>(), 12);
+    assert_eq!(values.clone().into_iter().sum::<Bits<2>>(), 12);
+    assert_eq!(values.iter().product::<Bits<2>>(), 60);
+    assert_eq!(values.into_iter().product::<Bits<2>>(), 60);
+    assert_eq!(std::iter::empty::<Bits<2>>().sum::<Bits<2>>(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Overflow in `add`")]
+fn test_bits_sum_overflow() {
+    let _: Bits<1> = [Bits([200]), Bits([100])].iter().sum();
+}
+
+#[test]
+fn test_bits_wide_primitive() {
+    // operands wider than N bytes are not truncated
+    let a = Bits([5]);
+    assert_eq!(&a / 256_u64, Bits([0]));
+    assert_eq!(&a % 256_u64, Bits([5]));
+    assert_eq!(&a % 0x1_0000_0005_u64, Bits([5]));
+    assert_eq!(Bits([0]) * 256_u64, Bits([0]));
+    assert_eq!(Bits([0, 0, 5]) + 0x1_00_u64, Bits([0, 1, 5]));
+    assert_eq!(Bits::<0>::new() + 0_u8, Bits::<0>::new());
+
+    let mut x = Bits([0, 5]);
+    x %= u64::MAX;
+    x /= 1_u32 << 16;
+    assert_eq!(x, 0);
+}
+
+#[test]
+#[should_panic(expected = "Overflow in `add`")]
+fn test_bits_wide_primitive_add() {
+    let _ = Bits([5]) + 256_u64;
+}
+
+#[test]
+#[should_panic(expected = "Overflow in `sub_assign`")]
+fn test_bits_wide_primitive_sub() {
+    let mut x = Bits([0xff, 0xff]);
+    x -= 0x1_0000_u32;
+}
+
+#[test]
+#[should_panic(expected = "Overflow in `mul`")]
+fn test_bits_wide_primitive_mul() {
+    let _ = &Bits([1]) * 256_u16;
+}
+
+#[test]
+#[should_panic(expected = "`NBits<0>` can't hold 1")]
+fn test_bits_product_zero_width() {
+    let _: Bits<0> = std::iter::empty::<Bits<0>>().product();
+}
+
+#[test]
+#[should_panic(expected = "`NBits<0>` can't hold 1")]
+fn test_bits_pow_zero_width() {
+    let _ = Bits::<0>::new().pow(0);
+}
@@ -0,0 +1,114 @@
+#![cfg(test)]
+#![cfg(feature = "num-traits")]
+
+use nbits::NBits;
+use num_traits::{
+    checked_pow, Bounded, CheckedAdd, CheckedDiv, CheckedRem, CheckedSub, FromPrimitive, Num, One,
+    Pow, Saturating, ToPrimitive, WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+#[test]
+fn test_num_identities() {
+    assert_eq!(NBits::<3>::zero(), NBits([0, 0, 0]));
+    assert_eq!(NBits::<3>::one(), NBits([0, 0, 1]));
+    assert!(!NBits([0, 1, 0]).is_zero());
+    assert_eq!(NBits::<2>::min_value(), NBits([0, 0]));
+    assert_eq!(NBits::<2>::max_value(), NBits([0xff, 0xff]));
+}
+
+#[test]
+fn test_num_from_str_radix() {
+    for radix in [2, 8, 10, 16, 36] {
+        for v in [0_u32, 1, 35, 255, 256, 0x12_3456] {
+            let str = num_format(v, radix);
+            let value = NBits::<3>::from_str_radix(&str, radix).unwrap();
+            assert_eq!(value.to_u32(), Some(v), "{str} radix {radix}");
+        }
+    }
+    assert_eq!(
+        NBits::<2>::from_str_radix("FFFF", 16),
+        Ok(NBits([0xff, 0xff]))
+    );
+    assert!(NBits::<2>::from_str_radix("10000", 16).is_err());
+    assert!(NBits::<2>::from_str_radix("12", 2).is_err());
+    assert!(NBits::<2>::from_str_radix("1_0", 10).is_err());
+    assert!(NBits::<2>::from_str_radix("", 10).is_err());
+}
+
+fn num_format(mut v: u32, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit(v % radix, radix).unwrap());
+        v /= radix;
+        if v == 0 {
+            return digits.iter().rev().collect();
+        }
+    }
+}
+
+#[test]
+fn test_num_checked_wrapping() {
+    for a in (0..=u16::MAX).step_by(997) {
+        for b in (0..=u16::MAX).step_by(1009) {
+            let (x, y) = (NBits(a.to_be_bytes()), NBits(b.to_be_bytes()));
+            let value = |v: Option<u16>| v.map(|v| NBits(v.to_be_bytes()));
+            assert_eq!(x.checked_add(&y), value(a.checked_add(b)));
+            assert_eq!(x.checked_sub(&y), value(a.checked_sub(b)));
+            assert_eq!(
+                num_traits::CheckedMul::checked_mul(&x, &y),
+                value(a.checked_mul(b))
+            );
+            assert_eq!(x.checked_div(&y), value(a.checked_div(b)));
+            assert_eq!(x.checked_rem(&y), value(a.checked_rem(b)));
+            assert_eq!(x.wrapping_add(&y).0, a.wrapping_add(b).to_be_bytes());
+            assert_eq!(x.wrapping_sub(&y).0, a.wrapping_sub(b).to_be_bytes());
+            assert_eq!(x.wrapping_mul(&y).0, a.wrapping_mul(b).to_be_bytes());
+            assert_eq!(
+                x.clone().saturating_add(y.clone()).0,
+                a.saturating_add(b).to_be_bytes()
+            );
+            assert_eq!(x.saturating_sub(y).0, a.saturating_sub(b).to_be_bytes());
+        }
+    }
+}
+
+#[test]
+fn test_num_primitive() {
+    let value = NBits([0, 0, 0, 0, 0, 0, 0, 0, 0x80, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(value.to_u64(), Some(0x8000_0000_0000_0001));
+    assert_eq!(value.to_i64(), None);
+    assert_eq!(value.to_u128(), Some(0x8000_0000_0000_0001));
+    assert_eq!(NBits([1, 0, 0, 0, 0, 0, 0, 0, 0]).to_u64(), None);
+    assert_eq!(NBits([0x12, 0x34]).to_u8(), None);
+    assert_eq!(NBits([0x12, 0x34]).to_i32(), Some(0x1234));
+
+    assert_eq!(NBits::<2>::from_u64(0x1234), Some(NBits([0x12, 0x34])));
+    assert_eq!(NBits::<2>::from_u64(0x1_0000), None);
+    assert_eq!(NBits::<2>::from_i32(-1), None);
+    assert_eq!(
+        NBits::<17>::from_u128(u128::MAX).unwrap().to_u128(),
+        Some(u128::MAX)
+    );
+}
+
+#[test]
+fn test_num_pow() {
+    assert_eq!(Pow::pow(NBits([0, 0, 2]), 16_u32), NBits([1, 0, 0]));
+    assert_eq!(Pow::pow(&NBits([0, 0, 7]), 0_u32), 1);
+    assert_eq!(Pow::pow(&NBits([0, 0, 0]), 5_u32), 0);
+    assert_eq!(NBits([0, 0, 3]).pow(13), 3_u64.pow(13));
+    assert_eq!(checked_pow(NBits([0, 2]), 16), None);
+    assert_eq!(checked_pow(NBits([0, 2]), 15), Some(NBits([0x80, 0])));
+}
+
+#[test]
+#[should_panic]
+fn test_num_pow_overflow() {
+    let _ = NBits([0, 2]).pow(16);
+}
+
+#[test]
+#[should_panic(expected = "`NBits<0>` can't hold 1")]
+fn test_num_one_zero_width() {
+    let _ = NBits::<0>::one();
+}
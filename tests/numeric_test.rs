@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use nbits::{NBits, Numeric, XBits};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn hash(value: &Numeric) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_numeric_cmp() {
+    let values = [
+        0_u128,
+        1,
+        255,
+        256,
+        0x1234,
+        0xffff_ffff,
+        u64::MAX as u128 + 1,
+        u128::MAX,
+    ];
+    for &a in &values {
+        for &b in &values {
+            // widened by one zero byte at the most significant end
+            let (a_be, b_be) = (a.to_be_bytes(), [&[0][..], &b.to_be_bytes()].concat());
+            let (a_le, b_le) = (a.to_le_bytes(), [&b.to_le_bytes()[..], &[0]].concat());
+            let a_short = &a_be[a.leading_zeros() as usize / 8..];
+
+            assert_eq!(a_be.bits().numeric_cmp(b_be.bits()), a.cmp(&b));
+            assert_eq!(a_short.bits().numeric_cmp(b_be.bits()), a.cmp(&b));
+            assert_eq!(a_le.bits().numeric_le_cmp(b_le.bits()), a.cmp(&b));
+
+            let (x, y) = (Numeric::Be(a_short), Numeric::Le(&b_le));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!(x == y, a == b);
+            if a == b {
+                assert_eq!(hash(&x), hash(&y));
+            }
+
+            assert_eq!(NBits(a_be).numeric_cmp_int(b), a.cmp(&b));
+            assert_eq!(NBits(a_be).numeric_cmp(&NBits(b.to_be_bytes())), a.cmp(&b));
+        }
+    }
+}
+
+#[test]
+fn test_numeric_collections() {
+    let keys: [&[u8]; 5] = [&[0, 0, 2], &[2], &[1, 0], &[0], &[]];
+    let sorted: Vec<_> = keys
+        .iter()
+        .map(|key| Numeric::Be(key))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|key| key.significant_len())
+        .collect();
+    assert_eq!(sorted, [0, 1, 2]);
+
+    let set: HashSet<_> = keys.iter().map(|key| key.bits().numeric()).collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&Numeric::Le(&[0, 1])));
+    assert!(set.contains(&NBits([0; 4]).numeric()));
+}
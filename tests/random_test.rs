@@ -0,0 +1,100 @@
+#![cfg(test)]
+#![cfg(feature = "rand")]
+
+use core::ops::Bound;
+use nbits::{NBits, XBits};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+#[test]
+fn test_random_standard() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let (a, b): (NBits<16>, NBits<16>) = (rng.gen(), rng.gen());
+    assert_ne!(a, b);
+
+    // each bit is set about half the time
+    let ones: usize = (0..1000)
+        .map(|_| rng.gen::<NBits<4>>().0.bits().count_ones())
+        .sum();
+    assert!((15_000..17_000).contains(&ones), "{ones}");
+}
+
+#[test]
+fn test_random_below() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let mut counts = [0; 3];
+    for _ in 0..3000 {
+        let value = NBits::random_below(&NBits([3]), &mut rng);
+        counts[value.0[0] as usize] += 1;
+    }
+    assert!(counts.iter().all(|c| (900..1100).contains(c)), "{counts:?}");
+
+    // a bound just above a power of two, in the high byte
+    let bound = NBits([1, 0, 1]);
+    let mut high = 0;
+    for _ in 0..1000 {
+        let value = NBits::random_below(&bound, &mut rng);
+        assert!(value < bound);
+        high += value.0[0] as usize;
+    }
+    assert_eq!(high, 0);
+
+    assert_eq!(NBits::random_below(&NBits([0, 1]), &mut rng), NBits([0, 0]));
+}
+
+#[test]
+#[should_panic]
+fn test_random_below_zero() {
+    NBits::random_below(&NBits([0, 0]), &mut SmallRng::seed_from_u64(7));
+}
+
+#[test]
+fn test_random_range() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let (low, high) = (NBits([0x12, 0xfe]), NBits([0x13, 0x01]));
+    let mut seen = [false; 4];
+    for _ in 0..200 {
+        let value = NBits::random_range(low.clone()..=high.clone(), &mut rng);
+        assert!(low <= value && value <= high);
+        seen[(value.0[1].wrapping_sub(0xfe)) as usize] = true;
+    }
+    assert_eq!(seen, [true; 4]);
+
+    for _ in 0..200 {
+        let value = NBits::random_range(low.clone()..high.clone(), &mut rng);
+        assert!(low <= value && value < high);
+        let value = NBits::random_range(..=NBits([0, 1]), &mut rng);
+        assert!(value <= 1);
+    }
+    assert_eq!(
+        NBits::random_range(
+            (
+                Bound::Excluded(low.clone()),
+                Bound::Excluded(NBits([0x13, 0x00]))
+            ),
+            &mut rng
+        ),
+        NBits([0x12, 0xff])
+    );
+    let _: NBits<2> = NBits::random_range(.., &mut rng);
+    assert_eq!(
+        NBits::random_range(NBits([0xff])..=NBits([0xff]), &mut rng),
+        NBits([0xff])
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_random_range_empty() {
+    NBits::random_range(NBits([5])..NBits([5]), &mut SmallRng::seed_from_u64(7));
+}
+
+#[test]
+fn test_fill_random() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+    a.bits_mut().fill_random(&mut rng);
+    b.bits_mut().fill_random(&mut rng).shr(8);
+    assert_ne!(a, [0; 32]);
+    assert_eq!(b[0], 0);
+    assert_ne!(a, b);
+}
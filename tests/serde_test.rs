@@ -0,0 +1,122 @@
+#![cfg(test)]
+#![cfg(feature = "serde")]
+
+use nbits::{BitVec, NBits, UBits};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn test_serde_nbits() {
+    let value = NBits([0x00, 0x12, 0xab]);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#""0x0012ab""#);
+    assert_eq!(serde_json::from_str::<NBits<3>>(&json).unwrap(), value);
+    assert_eq!(
+        serde_json::from_str::<NBits<3>>(r#""0x12ab""#).unwrap(),
+        value
+    );
+    assert_eq!(
+        serde_json::from_str::<NBits<3>>(r#""4779""#).unwrap(),
+        value
+    );
+    assert_eq!(serde_json::from_str::<NBits<3>>("4779").unwrap(), value);
+    assert_eq!(serde_json::to_string(&NBits([])).unwrap(), r#""0x0""#);
+    assert_eq!(
+        serde_json::from_str::<NBits<0>>(r#""0x0""#).unwrap(),
+        NBits([])
+    );
+
+    assert!(serde_json::from_str::<NBits<1>>(r#""0x12ab""#).is_err());
+    assert!(serde_json::from_str::<NBits<1>>("256").is_err());
+    assert!(serde_json::from_str::<NBits<3>>(r#""0x12ag""#).is_err());
+    assert!(serde_json::from_str::<NBits<3>>(r#""""#).is_err());
+
+    let bytes = bincode::serialize(&value).unwrap();
+    assert_eq!(bytes, [0x00, 0x12, 0xab]);
+    assert_eq!(bincode::deserialize::<NBits<3>>(&bytes).unwrap(), value);
+    assert!(bincode::deserialize::<NBits<4>>(&bytes).is_err());
+}
+
+#[test]
+fn test_serde_ubits() {
+    type U12 = UBits<12, 2>;
+    let json = serde_json::to_string(&U12::max()).unwrap();
+    assert_eq!(json, r#""0x0fff""#);
+    assert_eq!(serde_json::from_str::<U12>(&json).unwrap(), U12::max());
+    assert!(serde_json::from_str::<U12>(r#""0x1000""#).is_err());
+
+    let bytes = bincode::serialize(&U12::max()).unwrap();
+    assert_eq!(bincode::deserialize::<U12>(&bytes).unwrap(), U12::max());
+    assert!(bincode::deserialize::<U12>(&[0x10, 0x00]).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "nbits::serde::decimal")]
+    limit: NBits<32>,
+    #[serde(with = "nbits::serde::decimal")]
+    small: UBits<12, 2>,
+    mask: NBits<2>,
+}
+
+#[test]
+fn test_serde_decimal() {
+    let config = Config {
+        limit: NBits::from_literal(
+            "115792089237316195423570985008687907852837564279074904382605163141518161494337",
+        ),
+        small: UBits::max(),
+        mask: NBits([0, 0]),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"limit":"115792089237316195423570985008687907852837564279074904382605163141518161494337","small":"4095","mask":"0x0000"}"#
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+    for literal in [
+        "0",
+        "1",
+        "999999999",
+        "1000000000",
+        "1000000001",
+        "18446744073709551616",
+    ] {
+        let value = Config {
+            limit: NBits::from_literal(literal),
+            small: UBits::new(),
+            mask: NBits([0xff, 0xff]),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert!(json.starts_with(&format!(r#"{{"limit":"{literal}""#)));
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), value);
+    }
+
+    let bytes = bincode::serialize(&config).unwrap();
+    assert_eq!(bytes.len(), 32 + 2 + 2);
+    assert_eq!(bincode::deserialize::<Config>(&bytes).unwrap(), config);
+}
+
+#[test]
+fn test_serde_bitvec() {
+    let bits: BitVec = [true, false, true, true, false, false, false, false, true]
+        .into_iter()
+        .collect();
+    let json = serde_json::to_string(&bits).unwrap();
+    assert_eq!(json, r#""101100001""#);
+    assert_eq!(serde_json::from_str::<BitVec>(&json).unwrap(), bits);
+    assert!(serde_json::from_str::<BitVec>(r#""1012""#).is_err());
+
+    let bytes = bincode::serialize(&bits).unwrap();
+    assert_eq!(bincode::deserialize::<BitVec>(&bytes).unwrap(), bits);
+    assert_eq!(
+        bincode::deserialize::<BitVec>(&bincode::serialize(&BitVec::new()).unwrap()).unwrap(),
+        BitVec::new()
+    );
+
+    // the unused bits must be zero and the bytes must fit the length
+    let dirty = bincode::serialize(&(9_u64, vec![0xb0_u8, 0xc0])).unwrap();
+    assert!(bincode::deserialize::<BitVec>(&dirty).is_err());
+    let short = bincode::serialize(&(17_u64, vec![0xb0_u8, 0x80])).unwrap();
+    assert!(bincode::deserialize::<BitVec>(&short).is_err());
+}
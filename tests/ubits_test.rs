@@ -0,0 +1,125 @@
+#![cfg(test)]
+#![cfg(feature = "alloc")]
+
+use nbits::{Error, NBits, UBits};
+
+type U100 = UBits<100, 13>;
+
+fn u100(value: u128) -> U100 {
+    let bytes = value.to_be_bytes()[3..].try_into().unwrap();
+    U100::from_nbits_wrapping(NBits(bytes))
+}
+
+fn value(ubits: &U100) -> u128 {
+    ubits
+        .as_bytes()
+        .iter()
+        .fold(0, |acc, &byte| acc << 8 | byte as u128)
+}
+
+const MOD: u128 = 1 << 100;
+
+fn samples() -> Vec<u128> {
+    let mut values = vec![0, 1, 2, 3, 6, 255, 256, MOD - 1, MOD - 2, MOD / 2, MOD / 3];
+    let mut x = 0x1234_5678_9abc_def0_u128;
+    for _ in 0..20 {
+        x = x
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        values.push(x % MOD);
+        values.push((x >> 64) % 1_000_000);
+    }
+    values
+}
+
+#[test]
+fn test_ubits_arith() {
+    for &a in &samples() {
+        for &b in &samples() {
+            let (x, y) = (u100(a), u100(b));
+            assert_eq!(value(&x.wrapping_add(&y)), (a + b) % MOD);
+            assert_eq!(value(&x.wrapping_sub(&y)), (a + MOD - b) % MOD);
+            assert_eq!(value(&x.wrapping_mul(&y)), a.wrapping_mul(b) % MOD);
+
+            let sum = (a + b < MOD).then(|| u100(a + b));
+            assert_eq!(x.checked_add(&y), sum);
+            assert_eq!(x.checked_sub(&y), a.checked_sub(b).map(u100));
+            let product = a.checked_mul(b).filter(|&p| p < MOD).map(u100);
+            assert_eq!(x.checked_mul(&y), product);
+            assert_eq!(x.checked_div(&y), a.checked_div(b).map(u100));
+            assert_eq!(x.checked_rem(&y), a.checked_rem(b).map(u100));
+
+            assert_eq!(value(&(x.clone() & &y)), a & b);
+            assert_eq!(value(&(x.clone() | &y)), a | b);
+            assert_eq!(value(&(x.clone() ^ &y)), a ^ b);
+            assert_eq!(x < y, a < b);
+        }
+        let x = u100(a);
+        assert_eq!(value(&!x.clone()), !a % MOD);
+        assert_eq!(value(&(x.clone() << 7)), (a << 7) % MOD);
+        assert_eq!(value(&(x.clone() >> 7)), a >> 7);
+        assert_eq!(x.leading_zeros(), a.leading_zeros() as usize - 28);
+        assert_eq!(x.count_ones(), a.count_ones() as usize);
+        assert_eq!(x.bit(99), a & 1 == 1);
+        assert_eq!(x.bit(0), a >> 99 == 1);
+    }
+}
+
+#[test]
+fn test_ubits_operators() {
+    let mut x = u100(MOD - 2);
+    x += &U100::one();
+    assert_eq!(x, U100::max());
+    x -= &U100::max();
+    assert_eq!(x, U100::new());
+    x = u100(12);
+    x *= &u100(5);
+    x /= &u100(7);
+    x %= &u100(5);
+    assert_eq!(value(&x), 60 / 7 % 5);
+    x <<= 98;
+    assert_eq!(value(&x), 3 << 98);
+    x <<= 1;
+    assert_eq!(value(&x), 1 << 99);
+    x >>= 99;
+    assert_eq!(x, U100::one());
+}
+
+#[test]
+#[should_panic(expected = "Overflow in `add`")]
+fn test_ubits_add_overflow() {
+    let _ = U100::max() + &U100::one();
+}
+
+#[test]
+#[should_panic(expected = "Overflow in `mul`")]
+fn test_ubits_mul_overflow() {
+    let _ = u100(1 << 50) * &u100(1 << 50);
+}
+
+#[test]
+fn test_ubits_nbits() {
+    type U12 = UBits<12, 2>;
+    assert_eq!(U12::BITS, 12);
+    assert_eq!(U12::try_from(NBits([0x0f, 0xff])), Ok(U12::max()));
+    assert_eq!(
+        U12::try_from(NBits([0x1f, 0xff])),
+        Err(Error::WidthOverflow {
+            bits: 13,
+            width: 12
+        })
+    );
+    assert_eq!(NBits::from(U12::max()), NBits([0x0f, 0xff]));
+    assert_eq!(U12::max().as_nbits(), &NBits([0x0f, 0xff]));
+    assert_eq!(U12::max().bits().count_ones(), 12);
+    assert_eq!(U12::max().as_ref(), [0x0f, 0xff]);
+
+    // byte aligned widths have no unused bits
+    type U64 = UBits<64, 8>;
+    let max = U64::max();
+    assert_eq!(max.as_bytes(), [0xff; 8]);
+    assert_eq!(max.checked_add(&U64::one()), None);
+    assert_eq!(max.wrapping_add(&U64::one()), U64::new());
+    assert_eq!((!U64::new()), max);
+    assert_eq!(U64::new().checked_sub(&U64::one()), None);
+}
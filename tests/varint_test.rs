@@ -0,0 +1,208 @@
+#![cfg(test)]
+#![cfg(feature = "std")]
+
+use nbits::core::{BitCursor, BitCursorMut};
+use nbits::io::{BitReader, BitWriter};
+use nbits::varint::{zigzag_decode, zigzag_encode, VarintRead, VarintWrite};
+use nbits::{Error, NBits};
+use std::io::{Cursor, ErrorKind};
+
+fn uleb128(mut value: u128) -> Vec<u8> {
+    let mut out = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        match value {
+            0 => return [out, vec![byte]].concat(),
+            _ => out.push(byte | 0x80),
+        }
+    }
+}
+
+fn sleb128(mut value: i128) -> Vec<u8> {
+    let mut out = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        match (value, byte & 0x40) {
+            (0, 0) | (-1, 0x40) => return [out, vec![byte]].concat(),
+            _ => out.push(byte | 0x80),
+        }
+    }
+}
+
+fn samples() -> Vec<u128> {
+    let mut values: Vec<u128> = (0..300).collect();
+    values.extend((1..128).flat_map(|n| [(1 << n) - 1, 1 << n, (1 << n) + 1]));
+    values.push(u128::MAX);
+    values
+}
+
+fn encode(f: impl FnOnce(&mut BitCursorMut) -> Result<(), Error>) -> Vec<u8> {
+    let mut data = [0_u8; 32];
+    let mut cursor = BitCursorMut::new(&mut data);
+    f(&mut cursor).unwrap();
+    let len = cursor.position() / 8;
+    data[..len].to_vec()
+}
+
+#[test]
+fn test_leb128() {
+    for value in samples() {
+        let (v64, i64v, i128v) = (value as u64, value as i64, value as i128);
+        assert_eq!(encode(|c| c.write_uleb128(v64)), uleb128(v64 as u128));
+        assert_eq!(encode(|c| c.write_sleb128(i64v)), sleb128(i64v as i128));
+        let wide = NBits(value.to_be_bytes());
+        assert_eq!(encode(|c| c.write_uleb128_nbits(&wide)), uleb128(value));
+        assert_eq!(encode(|c| c.write_sleb128_nbits(&wide)), sleb128(i128v));
+
+        let data = uleb128(v64 as u128);
+        assert_eq!(BitCursor::new(&data).read_uleb128(), Ok(v64));
+        let data = sleb128(i64v as i128);
+        assert_eq!(BitCursor::new(&data).read_sleb128(), Ok(i64v));
+        let data = uleb128(value);
+        assert_eq!(BitCursor::new(&data).read_uleb128_nbits(), Ok(wide.clone()));
+        let data = sleb128(i128v);
+        assert_eq!(BitCursor::new(&data).read_sleb128_nbits(), Ok(wide));
+    }
+
+    // widths not multiple of 7 bits
+    let edges = [-(1_i32 << 23), -1, 0, (1 << 23) - 1];
+    for value in (-(1 << 23)..(1 << 23)).step_by(97).chain(edges) {
+        let wide = NBits::<3>(value.to_be_bytes()[1..].try_into().unwrap());
+        let data = sleb128(value as i128);
+        assert_eq!(encode(|c| c.write_sleb128_nbits(&wide)), data);
+        assert_eq!(BitCursor::new(&data).read_sleb128_nbits(), Ok(wide.clone()));
+        let data = uleb128(value as u128 & 0xff_ffff);
+        assert_eq!(encode(|c| c.write_uleb128_nbits(&wide)), data);
+        assert_eq!(BitCursor::new(&data).read_uleb128_nbits(), Ok(wide));
+    }
+}
+
+#[test]
+fn test_leb128_strict() {
+    // non-minimal encodings
+    for data in [&[0x80, 0x00][..], &[0xff, 0x80, 0x00], &[0x80; 9]] {
+        let result = BitCursor::new(&[data, &[0x00]].concat()).read_uleb128();
+        assert_eq!(result, Err(Error::NonMinimal));
+    }
+    assert_eq!(
+        BitCursor::new(&[0x80, 0x00]).read_sleb128(),
+        Err(Error::NonMinimal)
+    );
+    assert_eq!(
+        BitCursor::new(&[0xff, 0x7f]).read_sleb128(),
+        Err(Error::NonMinimal)
+    );
+    assert_eq!(
+        BitCursor::new(&[0xbf, 0x00]).read_sleb128(),
+        Err(Error::NonMinimal)
+    );
+    assert_eq!(BitCursor::new(&[0xc0, 0x00]).read_sleb128(), Ok(0x40));
+    assert_eq!(BitCursor::new(&[0xbf, 0x7f]).read_sleb128(), Ok(-0x41));
+
+    // values overflow the decoded type
+    let data = [&[0xff; 9][..], &[0x01]].concat();
+    assert_eq!(BitCursor::new(&data).read_uleb128(), Ok(u64::MAX));
+    let data = [&[0xff; 9][..], &[0x02]].concat();
+    assert_eq!(
+        BitCursor::new(&data).read_uleb128(),
+        Err(Error::InvalidCode)
+    );
+    let data = [&[0x80; 10][..], &[0x01]].concat();
+    assert_eq!(
+        BitCursor::new(&data).read_uleb128(),
+        Err(Error::InvalidCode)
+    );
+    let data = [&[0xff; 9][..], &[0x01]].concat();
+    assert_eq!(
+        BitCursor::new(&data).read_sleb128(),
+        Err(Error::InvalidCode)
+    );
+    let data = [&[0x80; 9][..], &[0x7f]].concat();
+    assert_eq!(BitCursor::new(&data).read_sleb128(), Ok(i64::MIN));
+    let data = [&[0x80; 9][..], &[0x7e]].concat();
+    assert_eq!(
+        BitCursor::new(&data).read_sleb128(),
+        Err(Error::InvalidCode)
+    );
+    assert_eq!(
+        BitCursor::new(&[0x80, 0x02]).read_uleb128_nbits::<1>(),
+        Err(Error::InvalidCode)
+    );
+    assert_eq!(
+        BitCursor::new(&[0x80, 0x01]).read_sleb128_nbits::<1>(),
+        Err(Error::InvalidCode)
+    );
+
+    // truncated encodings
+    assert!(matches!(
+        BitCursor::new(&[0x80]).read_uleb128(),
+        Err(Error::OutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn test_compact_size() {
+    let cases: [(u64, &[u8]); 8] = [
+        (0, &[0x00]),
+        (0xfc, &[0xfc]),
+        (0xfd, &[0xfd, 0xfd, 0x00]),
+        (0xffff, &[0xfd, 0xff, 0xff]),
+        (0x1_0000, &[0xfe, 0x00, 0x00, 0x01, 0x00]),
+        (0xffff_ffff, &[0xfe, 0xff, 0xff, 0xff, 0xff]),
+        (0x1_0000_0000, &[0xff, 0, 0, 0, 0, 1, 0, 0, 0]),
+        (u64::MAX, &[0xff; 9]),
+    ];
+    for (value, data) in cases {
+        assert_eq!(encode(|c| c.write_compact_size(value)), data);
+        assert_eq!(BitCursor::new(data).read_compact_size(), Ok(value));
+    }
+
+    for data in [
+        &[0xfd, 0xfc, 0x00][..],
+        &[0xfe, 0xff, 0xff, 0x00, 0x00],
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0],
+    ] {
+        let result = BitCursor::new(data).read_compact_size();
+        assert_eq!(result, Err(Error::NonMinimal));
+    }
+    assert!(BitCursor::new(&[0xfe, 0x00]).read_compact_size().is_err());
+}
+
+#[test]
+fn test_varint_stream() {
+    let mut writer = BitWriter::new(vec![]);
+    writer.write_bits(0b101, 3).unwrap();
+    writer.write_uleb128(u64::MAX).unwrap();
+    writer
+        .write_sleb128(zigzag_decode(zigzag_encode(-3)))
+        .unwrap();
+    writer.write_compact_size(0x1234).unwrap();
+    writer.write_uleb128_nbits(&NBits([0x01; 20])).unwrap();
+    writer.pad_to_byte(false).unwrap();
+
+    let mut reader = BitReader::new(Cursor::new(writer.into_inner()));
+    assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+    assert_eq!(reader.read_uleb128().unwrap(), u64::MAX);
+    assert_eq!(reader.read_sleb128().unwrap(), -3);
+    assert_eq!(reader.read_compact_size().unwrap(), 0x1234);
+    assert_eq!(reader.read_uleb128_nbits().unwrap(), NBits([0x01; 20]));
+    let err = reader.read_uleb128_nbits::<4>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    let mut reader = BitReader::new(Cursor::new([0x80, 0x00]));
+    let err = reader.read_uleb128().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_zigzag() {
+    for (value, code) in [(0, 0), (-1, 1), (1, 2), (-2, 3), (i64::MAX, u64::MAX - 1)] {
+        assert_eq!(zigzag_encode(value), code);
+        assert_eq!(zigzag_decode(code), value);
+    }
+    for value in [i64::MIN, i64::MIN + 1, -300, 300, i64::MAX] {
+        assert_eq!(zigzag_decode(zigzag_encode(value)), value);
+    }
+}